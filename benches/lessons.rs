@@ -0,0 +1,89 @@
+// cargo bench -- --save-baseline main   stores a baseline
+// cargo bench -- --baseline main        compares the current code against it
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use learning_rust::collections::ring;
+use learning_rust::iterators::Measurement;
+use learning_rust::{concurrency, iterators, smart_pointers};
+
+fn rayon(c: &mut Criterion) {
+    const TOTAL_ITEMS: usize = 1_200_000;
+    let mut group = c.benchmark_group("rayon");
+    group.bench_function("iter_mut", |b| {
+        b.iter_batched_ref(
+            || vec![0; TOTAL_ITEMS],
+            |items| concurrency::number_items(items),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("par_iter_mut", |b| {
+        b.iter_batched_ref(
+            || vec![0; TOTAL_ITEMS],
+            |items| concurrency::par_number_items(items),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn big_multithreading(c: &mut Criterion) {
+    let mut group = c.benchmark_group("big_multithreading");
+    for threads in [1, 2, 10] {
+        group.bench_function(format!("{threads}_threads"), |b| {
+            b.iter(|| concurrency::big_multithreading_work(black_box(1_000_000), threads))
+        });
+    }
+    group.finish();
+}
+
+fn iterators(c: &mut Criterion) {
+    let vector = (0..100_000).collect::<Vec<i32>>();
+    let mut group = c.benchmark_group("iterators");
+    group.bench_function("map_collect", |b| {
+        b.iter(|| iterators::plus_two(black_box(&vector)))
+    });
+    group.bench_function("clone_iter_mut", |b| {
+        b.iter(|| {
+            let mut vector_mut = black_box(&vector).clone();
+            iterators::plus_hundred_in_place(&mut vector_mut);
+            vector_mut
+        })
+    });
+    group.finish();
+}
+
+fn box_heap(c: &mut Criterion) {
+    let mut group = c.benchmark_group("box_heap");
+    group.bench_function("box_large_struct", |b| {
+        b.iter(smart_pointers::boxed_large_struct)
+    });
+    group.bench_function("vec_of_same_size", |b| {
+        b.iter(|| vec![0u8; black_box(1_000_000)])
+    });
+    group.finish();
+}
+
+fn ring_buffer(c: &mut Criterion) {
+    let values = concurrency::pipeline::measurement_lines(100_000)
+        .iter()
+        .filter_map(|line| Measurement::new(line))
+        .map(|measurement| measurement.value)
+        .collect::<Vec<f32>>();
+    let mut group = c.benchmark_group("ring_buffer");
+    group.bench_function("ring_buffer", |b| {
+        b.iter(|| ring::rolling_average(black_box(&values), 24))
+    });
+    group.bench_function("vecdeque", |b| {
+        b.iter(|| ring::rolling_average_vecdeque(black_box(&values), 24))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    rayon,
+    big_multithreading,
+    iterators,
+    box_heap,
+    ring_buffer
+);
+criterion_main!(benches);
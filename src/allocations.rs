@@ -0,0 +1,101 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::ops::Sub;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+// hands every request to the system allocator and counts it on the way, install it with
+// #[global_allocator], the runner does so with --features count-allocations
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn count(&self, bytes: usize) {
+        ACTIVE.store(true, Ordering::Relaxed);
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(bytes, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.count(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.count(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    // a Vec growing past its capacity ends up here, it counts as a new allocation
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.count(new_size);
+        System.realloc(pointer, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Allocations {
+    pub count: usize,
+    pub bytes: usize,
+}
+
+impl Sub for Allocations {
+    type Output = Allocations;
+
+    fn sub(self, earlier: Allocations) -> Allocations {
+        Allocations {
+            count: self.count - earlier.count,
+            bytes: self.bytes - earlier.bytes,
+        }
+    }
+}
+
+// false unless a CountingAllocator is installed, the counters then stay at zero
+pub fn counting() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
+
+// totals since the start of the program, over every thread
+pub fn snapshot() -> Allocations {
+    Allocations {
+        count: COUNT.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+    }
+}
+
+// other threads allocating at the same time are counted as well
+pub fn measure<T>(run: impl FnOnce() -> T) -> (T, Allocations) {
+    let before = snapshot();
+    let result = run();
+    (result, snapshot() - before)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_every_request_made_through_it() {
+        let layout = Layout::array::<u64>(4).unwrap();
+        let (_, allocations) = measure(|| unsafe {
+            let pointer = CountingAllocator.alloc(layout);
+            let pointer = CountingAllocator.realloc(pointer, layout, 64);
+            CountingAllocator.dealloc(pointer, Layout::from_size_align(64, 8).unwrap());
+        });
+        assert!(counting());
+        assert_eq!(
+            allocations,
+            Allocations {
+                count: 2,
+                bytes: 32 + 64
+            }
+        );
+    }
+}
@@ -0,0 +1,56 @@
+pub mod cancellation;
+// tokio leaves out tokio::net when built with --cfg loom, see tests/schedules.rs
+#[cfg(not(loom))]
+pub mod chat;
+pub mod executor;
+#[cfg(not(loom))]
+pub mod http;
+pub mod streams;
+pub mod tasks;
+
+pub async fn future_blocks() {
+    use std::time::Duration;
+    let future_1 = async {
+        std::thread::sleep(Duration::from_millis(15));
+        println!("async from 1")
+    };
+
+    let future_2 = async {
+        std::thread::sleep(Duration::from_millis(5));
+        println!("async from 2")
+    };
+
+    ((), ()) = futures::join!(future_1, future_2)
+}
+
+pub fn futures() {
+    let future = future_blocks();
+    futures::executor::block_on(future);
+}
+
+pub fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+pub fn tokio() {
+    // the same future as in futures(), only now tokio's runtime polls it
+    runtime().block_on(future_blocks());
+
+    // std::thread::sleep blocks the whole worker thread, tokio::time::sleep
+    // gives it back to the runtime so both timers run at the same time
+    runtime().block_on(async {
+        use std::time::Duration;
+        let future_1 = async {
+            tokio::time::sleep(Duration::from_millis(15)).await;
+            println!("tokio from 1")
+        };
+        let future_2 = async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            println!("tokio from 2")
+        };
+        tokio::join!(future_1, future_2)
+    });
+}
@@ -0,0 +1,162 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+use tokio_util::sync::CancellationToken;
+
+use super::runtime;
+
+pub type Log = Arc<Mutex<Vec<String>>>;
+
+// a future can be dropped at any .await, Drop is the one place that always runs
+pub struct Cleanup {
+    name: &'static str,
+    log: Log,
+}
+
+impl Cleanup {
+    pub fn new(name: &'static str, log: &Log) -> Self {
+        log.lock().unwrap().push(format!("{name} started"));
+        Self {
+            name,
+            log: Arc::clone(log),
+        }
+    }
+}
+
+impl Drop for Cleanup {
+    fn drop(&mut self) {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("{} cleaned up", self.name));
+    }
+}
+
+pub async fn slow_job(name: &'static str, duration: Duration, log: Log) -> &'static str {
+    let _cleanup = Cleanup::new(name, &log);
+    sleep(duration).await;
+    log.lock().unwrap().push(format!("{name} finished"));
+    name
+}
+
+pub fn new_log() -> Log {
+    Arc::new(Mutex::new(vec![]))
+}
+
+pub fn entries(log: &Log) -> Vec<String> {
+    log.lock().unwrap().clone()
+}
+
+// timeout drops the inner future when the time is up
+pub async fn timed_out_job(log: &Log) -> Option<&'static str> {
+    timeout(
+        Duration::from_millis(10),
+        slow_job("download", Duration::from_secs(10), log.clone()),
+    )
+    .await
+    .ok()
+}
+
+// select! runs the branch that finishes first and drops the others
+pub async fn first_of_two(log: &Log) -> &'static str {
+    tokio::select! {
+        name = slow_job("fast mirror", Duration::from_millis(5), log.clone()) => name,
+        name = slow_job("slow mirror", Duration::from_secs(10), log.clone()) => name,
+    }
+}
+
+// an aborted task is dropped at its next .await, wherever that is
+pub async fn aborted_task(log: &Log) -> bool {
+    let task = tokio::spawn(slow_job("upload", Duration::from_secs(10), log.clone()));
+    sleep(Duration::from_millis(10)).await;
+    task.abort();
+    task.await.unwrap_err().is_cancelled()
+}
+
+// workers check the token between ticks, so they stop at a point of their choosing
+pub async fn graceful_shutdown(workers: usize, log: &Log) -> Vec<usize> {
+    let token = CancellationToken::new();
+    let handles = (0..workers)
+        .map(|worker| {
+            let token = token.clone();
+            let log = log.clone();
+            tokio::spawn(async move {
+                let mut ticks = 0;
+                let mut interval = tokio::time::interval(Duration::from_millis(5));
+                loop {
+                    tokio::select! {
+                        _ = token.cancelled() => break,
+                        _ = interval.tick() => ticks += 1,
+                    }
+                }
+                log.lock()
+                    .unwrap()
+                    .push(format!("worker {worker} stopped after {ticks} ticks"));
+                ticks
+            })
+        })
+        .collect::<Vec<_>>();
+
+    sleep(Duration::from_millis(30)).await;
+    token.cancel();
+
+    let mut ticks = vec![];
+    for handle in handles {
+        ticks.push(handle.await.unwrap());
+    }
+    ticks
+}
+
+pub fn cancellation() {
+    runtime().block_on(async {
+        let log = new_log();
+        println!("timeout: {:?}", timed_out_job(&log).await);
+        println!("select!: {}", first_of_two(&log).await);
+        println!("abort: cancelled {}", aborted_task(&log).await);
+        println!("shutdown: ticks {:?}", graceful_shutdown(3, &log).await);
+        for entry in entries(&log) {
+            println!("  {entry}");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn timeout_cleans_up_the_dropped_future() {
+        let log = new_log();
+        assert_eq!(timed_out_job(&log).await, None);
+        assert_eq!(
+            entries(&log),
+            vec!["download started", "download cleaned up"]
+        );
+    }
+
+    #[tokio::test]
+    async fn select_cleans_up_the_losing_branch() {
+        let log = new_log();
+        assert_eq!(first_of_two(&log).await, "fast mirror");
+        let entries = entries(&log);
+        assert!(entries.contains(&"fast mirror finished".to_string()));
+        assert!(entries.contains(&"slow mirror cleaned up".to_string()));
+        assert!(!entries.contains(&"slow mirror finished".to_string()));
+    }
+
+    #[tokio::test]
+    async fn abort_cleans_up_mid_flight() {
+        let log = new_log();
+        assert!(aborted_task(&log).await);
+        assert_eq!(entries(&log), vec!["upload started", "upload cleaned up"]);
+    }
+
+    #[tokio::test]
+    async fn every_worker_stops_on_shutdown() {
+        let log = new_log();
+        let ticks = graceful_shutdown(3, &log).await;
+        assert_eq!(ticks.len(), 3);
+        assert!(ticks.iter().all(|ticks| *ticks > 0));
+        assert_eq!(entries(&log).len(), 3);
+    }
+}
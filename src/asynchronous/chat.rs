@@ -0,0 +1,119 @@
+use std::io;
+use std::net::SocketAddr;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::task::JoinHandle;
+
+use super::runtime;
+
+// every line a client sends goes to every other connected client
+pub async fn serve(listener: TcpListener) -> io::Result<()> {
+    let (sender, _) = broadcast::channel::<(usize, String)>(64);
+    let mut next_id = 0;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        next_id += 1;
+        // subscribe before spawning so nothing sent after the welcome is missed
+        let receiver = sender.subscribe();
+        tokio::spawn(handle_client(stream, next_id, sender.clone(), receiver));
+    }
+}
+
+async fn handle_client(
+    stream: TcpStream,
+    id: usize,
+    sender: broadcast::Sender<(usize, String)>,
+    mut receiver: broadcast::Receiver<(usize, String)>,
+) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    writer
+        .write_all(format!("welcome client {id}\n").as_bytes())
+        .await?;
+
+    // both branches are cancel safe, whichever is not picked loses nothing
+    loop {
+        tokio::select! {
+            line = lines.next_line() => match line? {
+                Some(line) => {
+                    // only fails when nobody is listening, which is fine
+                    let _ = sender.send((id, line));
+                }
+                None => break,
+            },
+            message = receiver.recv() => match message {
+                Ok((from, line)) if from != id => {
+                    writer.write_all(format!("client {from}: {line}\n").as_bytes()).await?;
+                }
+                Ok(_) => {}
+                Err(RecvError::Lagged(missed)) => {
+                    writer.write_all(format!("missed {missed} messages\n").as_bytes()).await?;
+                }
+                Err(RecvError::Closed) => break,
+            },
+        }
+    }
+    Ok(())
+}
+
+// binds a free loopback port and serves on it in the background
+pub async fn start() -> io::Result<(SocketAddr, JoinHandle<io::Result<()>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let address = listener.local_addr()?;
+    Ok((address, tokio::spawn(serve(listener))))
+}
+
+pub struct ChatClient {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    writer: OwnedWriteHalf,
+}
+
+impl ChatClient {
+    // returns once the server's welcome arrived, so the client is subscribed
+    pub async fn connect(address: SocketAddr) -> io::Result<(ChatClient, String)> {
+        let (reader, writer) = TcpStream::connect(address).await?.into_split();
+        let mut client = ChatClient {
+            lines: BufReader::new(reader).lines(),
+            writer,
+        };
+        let welcome = client.next_line().await?;
+        Ok((client, welcome))
+    }
+
+    pub async fn send(&mut self, line: &str) -> io::Result<()> {
+        self.writer.write_all(format!("{line}\n").as_bytes()).await
+    }
+
+    pub async fn next_line(&mut self) -> io::Result<String> {
+        self.lines
+            .next_line()
+            .await?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "server hung up"))
+    }
+}
+
+pub fn chat_server() {
+    runtime().block_on(async {
+        let (address, server) = start().await.unwrap();
+        println!("chat server listening on {address}");
+
+        let (mut alice, welcome) = ChatClient::connect(address).await.unwrap();
+        println!("alice got: {welcome}");
+        let (mut bob, welcome) = ChatClient::connect(address).await.unwrap();
+        println!("bob got: {welcome}");
+        let (mut carol, welcome) = ChatClient::connect(address).await.unwrap();
+        println!("carol got: {welcome}");
+
+        alice.send("who brought the hapax?").await.unwrap();
+        println!("bob got: {}", bob.next_line().await.unwrap());
+        println!("carol got: {}", carol.next_line().await.unwrap());
+
+        bob.send("me, and the bluebox").await.unwrap();
+        println!("alice got: {}", alice.next_line().await.unwrap());
+        println!("carol got: {}", carol.next_line().await.unwrap());
+
+        server.abort();
+    });
+}
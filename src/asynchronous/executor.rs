@@ -0,0 +1,186 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+use std::time::Duration;
+
+use super::future_blocks;
+
+type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+// a task is a future plus a way to put itself back on the queue
+struct Task {
+    future: Mutex<Option<BoxedFuture>>,
+    queue: Sender<Arc<Task>>,
+}
+
+// waking a task means sending it to the executor again, Wake turns an Arc<Task> into a Waker
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        // the executor may already be gone, then there is nobody left to poll us
+        let _ = self.queue.send(Arc::clone(self));
+    }
+}
+
+#[derive(Clone)]
+pub struct Spawner {
+    queue: Sender<Arc<Task>>,
+}
+
+impl Spawner {
+    pub fn spawn(&self, future: impl Future<Output = ()> + Send + 'static) {
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(Box::pin(future))),
+            queue: self.queue.clone(),
+        });
+        self.queue.send(task).unwrap();
+    }
+}
+
+pub struct Executor {
+    queue: Receiver<Arc<Task>>,
+}
+
+pub fn executor_and_spawner() -> (Executor, Spawner) {
+    let (sender, receiver) = channel();
+    (Executor { queue: receiver }, Spawner { queue: sender })
+}
+
+impl Executor {
+    // polls tasks until every sender (spawners and tasks) is gone, returns the number of polls
+    pub fn run(self) -> usize {
+        let mut polls = 0;
+        while let Ok(task) = self.queue.recv() {
+            let mut slot = task.future.lock().unwrap();
+            // a finished task can still be woken by a stale waker
+            let Some(mut future) = slot.take() else {
+                continue;
+            };
+            let waker = Waker::from(Arc::clone(&task));
+            let mut context = Context::from_waker(&waker);
+            polls += 1;
+            if future.as_mut().poll(&mut context).is_pending() {
+                *slot = Some(future);
+            }
+        }
+        polls
+    }
+}
+
+// runs a single future to completion on a fresh executor
+pub fn block_on<T: Send + 'static>(future: impl Future<Output = T> + Send + 'static) -> T {
+    let output = Arc::new(Mutex::new(None));
+    let (executor, spawner) = executor_and_spawner();
+    let slot = Arc::clone(&output);
+    spawner.spawn(async move {
+        *slot.lock().unwrap() = Some(future.await);
+    });
+    drop(spawner);
+    executor.run();
+    let output = output.lock().unwrap().take();
+    output.unwrap()
+}
+
+#[derive(Default)]
+struct TimerState {
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+// completes after a duration, a helper thread does the waiting and then wakes the task
+pub struct TimerFuture {
+    state: Arc<Mutex<TimerState>>,
+}
+
+impl TimerFuture {
+    pub fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new(TimerState::default()));
+        let thread_state = Arc::clone(&state);
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut state = thread_state.lock().unwrap();
+            state.completed = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        TimerFuture { state }
+    }
+}
+
+impl Future for TimerFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.completed {
+            Poll::Ready(())
+        } else {
+            // the task may have moved to another waker since the last poll
+            state.waker = Some(context.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+// spawns one timer task per duration and returns the order in which they finished
+pub fn timers_in_order(durations: &[u64]) -> (Vec<u64>, usize) {
+    let finished = Arc::new(Mutex::new(vec![]));
+    let (executor, spawner) = executor_and_spawner();
+    for &millis in durations {
+        let finished = Arc::clone(&finished);
+        spawner.spawn(async move {
+            TimerFuture::new(Duration::from_millis(millis)).await;
+            finished.lock().unwrap().push(millis);
+        });
+    }
+    drop(spawner);
+    let polls = executor.run();
+    let finished = finished.lock().unwrap().clone();
+    (finished, polls)
+}
+
+pub fn executor() {
+    // the same future futures() hands to futures::executor::block_on
+    block_on(future_blocks());
+
+    let answer = block_on(async {
+        TimerFuture::new(Duration::from_millis(10)).await;
+        42
+    });
+    println!("timer future resolved to {answer}");
+
+    let (finished, polls) = timers_in_order(&[30, 10, 20]);
+    println!("timers finished in order {finished:?} after {polls} polls");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_on_returns_the_output() {
+        let answer = block_on(async {
+            TimerFuture::new(Duration::from_millis(1)).await;
+            42
+        });
+        assert_eq!(answer, 42);
+        block_on(future_blocks());
+    }
+
+    #[test]
+    fn timers_are_polled_only_when_woken() {
+        let (mut finished, polls) = timers_in_order(&[30, 10, 20]);
+        // timer threads a few ms apart can wake in any order on a busy machine
+        finished.sort();
+        assert_eq!(finished, vec![10, 20, 30]);
+        // once pending, once more after the wake; only once if the timer already fired
+        assert!((3..=6).contains(&polls), "{polls} polls");
+    }
+}
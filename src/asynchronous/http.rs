@@ -0,0 +1,208 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use super::runtime;
+use crate::iterators::{Measurement, MEASUREMENT_LOGS};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeasurementStats {
+    pub count: usize,
+    pub average: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+// stats per kind of measurement, e.g. "temperature"
+pub fn stats(measurements: &[Measurement]) -> BTreeMap<String, MeasurementStats> {
+    let mut values = BTreeMap::<String, Vec<f32>>::new();
+    for measurement in measurements {
+        values
+            .entry(measurement.measurement.clone())
+            .or_default()
+            .push(measurement.value);
+    }
+    values
+        .into_iter()
+        .map(|(measurement, values)| {
+            let stats = MeasurementStats {
+                count: values.len(),
+                average: values.iter().sum::<f32>() / values.len() as f32,
+                min: values.iter().copied().fold(f32::INFINITY, f32::min),
+                max: values.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+            };
+            (measurement, stats)
+        })
+        .collect()
+}
+
+fn json_error(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+// status code and json body for a request line such as "GET /measurements HTTP/1.1"
+pub fn route(request_line: &str, measurements: &[Measurement]) -> (u16, String) {
+    let parts = request_line.split_whitespace().collect::<Vec<&str>>();
+    let [method, path, version] = parts[..] else {
+        return (400, json_error("malformed request line"));
+    };
+    if !version.starts_with("HTTP/1.") {
+        return (400, json_error("only HTTP/1.x is supported"));
+    }
+    match (method, path) {
+        ("GET", "/measurements") => (200, serde_json::to_string(measurements).unwrap()),
+        ("GET", "/measurements/stats") => {
+            (200, serde_json::to_string(&stats(measurements)).unwrap())
+        }
+        (_, "/measurements" | "/measurements/stats") => (405, json_error("method not allowed")),
+        _ => (404, json_error("not found")),
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        _ => "Internal Server Error",
+    }
+}
+
+// one request per connection, the headers are read but not used
+async fn handle_connection(stream: TcpStream, measurements: &[Measurement]) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    let request_line = lines.next_line().await?.unwrap_or_default();
+    while let Some(header) = lines.next_line().await? {
+        if header.is_empty() {
+            break;
+        }
+    }
+
+    let (status, body) = route(&request_line, measurements);
+    let response = format!(
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        reason(status),
+        body.len()
+    );
+    writer.write_all(response.as_bytes()).await?;
+    writer.shutdown().await
+}
+
+pub async fn serve(listener: TcpListener, measurements: Arc<Vec<Measurement>>) -> io::Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let measurements = Arc::clone(&measurements);
+        tokio::spawn(async move {
+            if let Err(error) = handle_connection(stream, &measurements).await {
+                eprintln!("request failed: {error}");
+            }
+        });
+    }
+}
+
+pub async fn start(
+    measurements: Vec<Measurement>,
+) -> io::Result<(SocketAddr, JoinHandle<io::Result<()>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let address = listener.local_addr()?;
+    Ok((
+        address,
+        tokio::spawn(serve(listener, Arc::new(measurements))),
+    ))
+}
+
+// a tiny client: sends a GET and returns the status code and body
+pub async fn get(address: SocketAddr, path: &str) -> io::Result<(u16, String)> {
+    let mut stream = TcpStream::connect(address).await?;
+    stream
+        .write_all(format!("GET {path} HTTP/1.1\r\nHost: {address}\r\n\r\n").as_bytes())
+        .await?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await?;
+
+    let invalid = || io::Error::new(io::ErrorKind::InvalidData, "malformed response");
+    let (head, body) = response.split_once("\r\n\r\n").ok_or_else(invalid)?;
+    let status = head
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status| status.parse().ok())
+        .ok_or_else(invalid)?;
+    Ok((status, body.to_string()))
+}
+
+pub fn measurement_service() {
+    let measurements = MEASUREMENT_LOGS
+        .iter()
+        .filter_map(|line| Measurement::new(line))
+        .collect::<Vec<Measurement>>();
+
+    runtime().block_on(async {
+        let (address, server) = start(measurements).await.unwrap();
+        println!("serving measurements on http://{address}");
+        for path in ["/measurements", "/measurements/stats", "/devices"] {
+            let (status, body) = get(address, path).await.unwrap();
+            println!("GET {path} -> {status} {body}");
+        }
+        server.abort();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurements() -> Vec<Measurement> {
+        [
+            "20240917 temperature 19.0",
+            "20240916 temperature 21.0",
+            "20240916 humidity 60",
+        ]
+        .iter()
+        .filter_map(|line| Measurement::new(line))
+        .collect()
+    }
+
+    #[test]
+    fn routes_reject_unknown_paths_and_methods() {
+        assert_eq!(route("GET /devices HTTP/1.1", &[]).0, 404);
+        assert_eq!(route("POST /measurements HTTP/1.1", &[]).0, 405);
+        assert_eq!(route("GET /measurements", &[]).0, 400);
+        assert_eq!(route("", &[]).0, 400);
+    }
+
+    #[tokio::test]
+    async fn serves_measurements_and_stats_as_json() {
+        let (address, server) = start(measurements()).await.unwrap();
+
+        let (status, body) = get(address, "/measurements").await.unwrap();
+        assert_eq!(status, 200);
+        let served: Vec<Measurement> = serde_json::from_str(&body).unwrap();
+        assert_eq!(served, measurements());
+
+        let (status, body) = get(address, "/measurements/stats").await.unwrap();
+        assert_eq!(status, 200);
+        let served: BTreeMap<String, MeasurementStats> = serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            served["temperature"],
+            MeasurementStats {
+                count: 2,
+                average: 20.0,
+                min: 19.0,
+                max: 21.0
+            }
+        );
+        assert_eq!(served["humidity"].count, 1);
+
+        let (status, body) = get(address, "/nope").await.unwrap();
+        assert_eq!((status, body.as_str()), (404, r#"{"error":"not found"}"#));
+
+        server.abort();
+    }
+}
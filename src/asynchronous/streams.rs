@@ -0,0 +1,145 @@
+use futures::stream::{Stream, StreamExt};
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, Lines};
+
+use super::runtime;
+use crate::iterators::{Measurement, MEASUREMENT_LOGS};
+
+// returns Pending a number of times before it is ready, waking itself every time
+pub struct Countdown {
+    pub remaining: u32,
+    pub polls: u32,
+}
+
+impl Countdown {
+    pub fn new(from: u32) -> Self {
+        Countdown {
+            remaining: from,
+            polls: 0,
+        }
+    }
+}
+
+impl Future for Countdown {
+    type Output = u32;
+
+    fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<u32> {
+        self.polls += 1;
+        if self.remaining == 0 {
+            return Poll::Ready(self.polls);
+        }
+        self.remaining -= 1;
+        // without the wake nobody would ever poll us again
+        context.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+// reads one line per poll, lines that are not a measurement are skipped
+pub struct MeasurementStream<R> {
+    lines: Lines<R>,
+}
+
+impl<R: AsyncBufRead + Unpin> MeasurementStream<R> {
+    pub fn new(reader: R) -> Self {
+        MeasurementStream {
+            lines: reader.lines(),
+        }
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for MeasurementStream<R> {
+    type Item = io::Result<Measurement>;
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.lines).poll_next_line(context) {
+                Poll::Ready(Ok(Some(line))) => {
+                    if let Some(measurement) = Measurement::new(&line) {
+                        return Poll::Ready(Some(Ok(measurement)));
+                    }
+                }
+                Poll::Ready(Ok(None)) => return Poll::Ready(None),
+                Poll::Ready(Err(error)) => return Poll::Ready(Some(Err(error))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+pub fn measurement_log() -> String {
+    MEASUREMENT_LOGS.join("\n")
+}
+
+pub async fn temperatures<R: AsyncBufRead + Unpin>(reader: R) -> Vec<f32> {
+    MeasurementStream::new(reader)
+        .filter_map(|measurement| async move { measurement.ok() })
+        .filter(|measurement| futures::future::ready(measurement.measurement == "temperature"))
+        .map(|measurement| measurement.value)
+        .collect()
+        .await
+}
+
+async fn to_fahrenheit(celsius: f32) -> f32 {
+    // pretend every conversion is a slow lookup somewhere
+    tokio::time::sleep(Duration::from_millis(celsius as u64)).await;
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+// runs up to `limit` conversions at once, results arrive in the order they finish
+pub async fn fahrenheit_unordered<R: AsyncBufRead + Unpin>(reader: R, limit: usize) -> Vec<f32> {
+    MeasurementStream::new(reader)
+        .filter_map(|measurement| async move { measurement.ok() })
+        .map(|measurement| to_fahrenheit(measurement.value))
+        .buffer_unordered(limit)
+        .collect()
+        .await
+}
+
+pub fn streams() {
+    let polls = futures::executor::block_on(Countdown::new(3));
+    println!("countdown from 3 was ready after {polls} polls");
+
+    runtime().block_on(async {
+        let log = measurement_log();
+        let mut stream = MeasurementStream::new(log.as_bytes());
+        while let Some(measurement) = stream.next().await {
+            measurement.unwrap().print();
+        }
+        println!("temperatures: {:?}", temperatures(log.as_bytes()).await);
+        println!(
+            "fahrenheit, 4 at a time: {:?}",
+            fahrenheit_unordered(log.as_bytes(), 4).await
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn countdown_is_polled_once_per_step() {
+        assert_eq!(futures::executor::block_on(Countdown::new(0)), 1);
+        assert_eq!(futures::executor::block_on(Countdown::new(3)), 4);
+    }
+
+    #[tokio::test]
+    async fn stream_skips_lines_that_are_not_measurements() {
+        let log = measurement_log();
+        assert_eq!(
+            temperatures(log.as_bytes()).await,
+            vec![19.0, 18.0, 0.0, 21.0]
+        );
+
+        let mut converted = fahrenheit_unordered(log.as_bytes(), 4).await;
+        // buffer_unordered yields the quickest conversions first
+        assert_eq!(converted[0], 32.0);
+        converted.sort_by(f32::total_cmp);
+        assert_eq!(converted, vec![32.0, 64.4, 66.2, 69.8]);
+    }
+}
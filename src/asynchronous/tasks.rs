@@ -0,0 +1,200 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
+
+use super::runtime;
+
+// the async counterpart of channels()
+pub async fn mpsc_messages() -> Vec<&'static str> {
+    let (sender, mut receiver) = mpsc::channel(8);
+    let sender_clone = sender.clone();
+
+    tokio::spawn(async move {
+        sender.send("Send a &str").await.unwrap();
+    });
+    tokio::spawn(async move {
+        sender_clone.send("Send another &str").await.unwrap();
+    });
+
+    // no need to count handles, recv returns None once every sender is dropped
+    let mut messages = vec![];
+    while let Some(message) = receiver.recv().await {
+        messages.push(message);
+    }
+    messages
+}
+
+// a oneshot carries exactly one reply back to whoever asked
+pub async fn oneshot_reply(question: u32) -> u32 {
+    let (reply_sender, reply_receiver) = oneshot::channel();
+    tokio::spawn(async move {
+        reply_sender.send(question * 2).unwrap();
+    });
+    reply_receiver.await.unwrap()
+}
+
+// every subscriber gets its own copy of every message
+pub async fn broadcast_devices(subscribers: usize) -> Vec<Vec<String>> {
+    let (sender, _) = broadcast::channel(16);
+    let handles = (0..subscribers)
+        .map(|_| {
+            let mut receiver = sender.subscribe();
+            tokio::spawn(async move {
+                let mut seen = vec![];
+                while let Ok(device) = receiver.recv().await {
+                    seen.push(device);
+                }
+                seen
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for device in ["micromonsta", "hapax", "bluebox"] {
+        sender.send(device.to_string()).unwrap();
+    }
+    drop(sender);
+
+    let mut received = vec![];
+    for handle in handles {
+        received.push(handle.await.unwrap());
+    }
+    received
+}
+
+// a watch only keeps the latest value, a slow watcher skips the ones in between
+pub async fn watch_latest(updates: u32) -> Vec<u32> {
+    let (sender, mut receiver) = watch::channel(0);
+    let watcher = tokio::spawn(async move {
+        let mut seen = vec![];
+        while receiver.changed().await.is_ok() {
+            seen.push(*receiver.borrow_and_update());
+        }
+        seen
+    });
+
+    for value in 1..=updates {
+        sender.send(value).unwrap();
+    }
+    drop(sender);
+    watcher.await.unwrap()
+}
+
+pub fn async_channels() {
+    runtime().block_on(async {
+        println!("mpsc: {:?}", mpsc_messages().await);
+        println!("oneshot: 21 * 2 = {}", oneshot_reply(21).await);
+        println!("broadcast: {:?}", broadcast_devices(2).await);
+        println!("watch: {:?}", watch_latest(5).await);
+    });
+}
+
+// the async counterpart of multithreading(): tasks instead of threads
+pub async fn counter_with_tasks() -> i32 {
+    let my_number = Arc::new(tokio::sync::Mutex::new(0));
+    let mut join_handle_vec = vec![];
+
+    for task in 0..2 {
+        let my_number_clone = Arc::clone(&my_number);
+        join_handle_vec.push(tokio::spawn(async move {
+            for i in 0..10 {
+                *my_number_clone.lock().await += 1;
+                println!("printing task {} iteration {}", task, i);
+            }
+        }));
+    }
+
+    for handle in join_handle_vec {
+        handle.await.unwrap();
+    }
+    let total = *my_number.lock().await;
+    total
+}
+
+pub fn async_multithreading() {
+    let total = runtime().block_on(counter_with_tasks());
+    println!("counted to {total} with tasks");
+}
+
+// the guard is dropped before the next .await, so a std Mutex is fine (and cheaper)
+pub async fn std_mutex_between_awaits(tasks: i32) -> i32 {
+    let counter = Arc::new(std::sync::Mutex::new(0));
+    let handles = (0..tasks)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            tokio::spawn(async move {
+                *counter.lock().unwrap() += 1;
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    let total = *counter.lock().unwrap();
+    total
+}
+
+// here the guard lives across an .await: a std MutexGuard is not Send, so
+// tokio::spawn would not accept this task, and even on one thread it could
+// block the worker another task needs to make progress
+pub async fn tokio_mutex_across_await(tasks: i32) -> i32 {
+    let counter = Arc::new(tokio::sync::Mutex::new(0));
+    let handles = (0..tasks)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            tokio::spawn(async move {
+                let mut guard = counter.lock().await;
+                let current = *guard;
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                *guard = current + 1;
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.await.unwrap();
+    }
+    let total = *counter.lock().await;
+    total
+}
+
+pub fn async_mutexes() {
+    runtime().block_on(async {
+        println!(
+            "std::sync::Mutex, not held across .await: {}",
+            std_mutex_between_awaits(10).await
+        );
+        println!(
+            "tokio::sync::Mutex, held across .await: {}",
+            tokio_mutex_across_await(10).await
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn channels_deliver_every_message() {
+        let mut messages = mpsc_messages().await;
+        messages.sort();
+        assert_eq!(messages, vec!["Send a &str", "Send another &str"]);
+        assert_eq!(oneshot_reply(21).await, 42);
+        let expected = vec!["micromonsta", "hapax", "bluebox"];
+        assert_eq!(broadcast_devices(3).await, vec![expected; 3]);
+    }
+
+    #[tokio::test]
+    async fn watch_ends_on_the_latest_value() {
+        let seen = watch_latest(5).await;
+        assert_eq!(seen.last(), Some(&5));
+        assert!(seen.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn counters_count_every_increment() {
+        assert_eq!(counter_with_tasks().await, 20);
+        assert_eq!(std_mutex_between_awaits(10).await, 10);
+        assert_eq!(tokio_mutex_across_await(10).await, 10);
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt::Display;
+
+pub mod escape;
+pub mod hexdump;
+pub mod literals;
+pub mod text;
+
+pub fn types() -> char {
+    let value: u8 = 61;
+    let char = value as char;
+    println!("{char}");
+    let string = "hellö world";
+    println!("{string} has {} chars", string.chars().count());
+    println!("{string} has {} bytes", string.len());
+    char
+}
+
+pub fn mutability() -> i32 {
+    let mut i = 1_000_000;
+    i = i + 15 + 20;
+    println!("Hello, mutable int! {i}");
+    i
+}
+
+pub fn byte_manipulation() {
+    let data = b"abc";
+    println!("hex representation of data is {:x?}", data);
+}
+
+pub fn unicode() {
+    let thumbs = '👍';
+    println!("thumbs as hex: {:x?}", thumbs as u32);
+    println!("hex as thumbs: \u{1f44d}");
+}
+
+pub fn arrays() {
+    let fruits = ["Apple", "Pear", "Banana"];
+    let prefilled_with_apples = ["Apple"; 10];
+    let sliced_inclusive = &fruits[1..=2];
+    let sliced_exclusive = &prefilled_with_apples[2..=5];
+    println!("inclusive slice: {:?}", sliced_inclusive);
+    println!("exclusive slice: {:?}", sliced_exclusive);
+}
+
+pub fn looping_and_matching() {
+    let vector_of_tuples = vec![
+        ("synth", "micromonsta"),
+        ("controller", "hapax"),
+        ("mixer", "bluebox"),
+    ];
+
+    for device in vector_of_tuples {
+        match device {
+            ("synth", s) => println!("device {} is a synth", s),
+            ("controller", s) => println!("device {} is a controller", s),
+            _ => println!("device is something else"),
+        }
+    }
+}
+
+// the device match keeps its `_ => ()` arm, which clippy would turn into an if let
+#[allow(clippy::single_match)]
+pub fn structs_enums_and_impl() {
+    #[derive(Debug)]
+    enum DeviceType {
+        Synth,
+        Controller,
+        Mixer,
+    }
+
+    #[derive(Debug)]
+    struct Device {
+        device_type: DeviceType,
+        power_usage_amps: f32,
+        name: String,
+        connected: bool,
+        //name: &'static str //static lifetime, this referenced value should live on after this block
+    }
+
+    impl Device {
+        // takes unmutable reference to self
+        fn is_high_powered(&self) -> bool {
+            self.power_usage_amps > 2.0
+        }
+
+        // take mutable reference to self
+        fn connect(&mut self) {
+            self.connected = true;
+        }
+    }
+
+    use DeviceType::*;
+    let device0 = Device {
+        device_type: Synth,
+        power_usage_amps: 1.5,
+        name: "MicroMonsta".to_string(),
+        connected: false,
+    };
+    let device1 = Device {
+        device_type: Synth,
+        power_usage_amps: 0.5,
+        name: "MicroMonsta".to_string(),
+        connected: false,
+    };
+    let device2 = Device {
+        device_type: Controller,
+        power_usage_amps: 2.0,
+        name: "Hapax".to_string(),
+        connected: false,
+    };
+
+    let device3 = Device {
+        device_type: Mixer,
+        power_usage_amps: 2.0,
+        name: "Bluebox".to_string(),
+        connected: false,
+    };
+
+    let mut vector = vec![device0, device1, device2, device3];
+    // ownership of vector is passed to the for loop:
+    for device in &mut vector {
+        match device.is_high_powered() {
+            true => println!("low power {:?}, {}", device.device_type, device.name),
+            false => println!("high power {:?}, {}", device.device_type, device.name),
+        }
+
+        match device.device_type {
+            Synth => device.connect(),
+            _ => (),
+        }
+    }
+    println!("vector values mutated in for loop: {:?}", vector);
+}
+
+// take_fifth asks for a &Vec, clippy prefers a slice
+#[allow(clippy::ptr_arg)]
+pub fn option_and_handling() {
+    fn take_fifth(vector: &Vec<i32>) -> Option<i32> {
+        if vector.len() < 5 {
+            None
+        } else {
+            Some(vector[4])
+        }
+    }
+    let long_array = vec![1, 2, 3, 4, 5];
+    let short_array = vec![1, 2, 3];
+
+    println!("unwrapping some{}", take_fifth(&long_array).unwrap());
+    //println!("{}",take_fifth(short_array).unwrap()); -> will crash with panic (unwrap None)
+
+    println!(
+        "unwrapping none, falling back to value {}",
+        take_fifth(&short_array).unwrap_or(0)
+    );
+
+    let number = take_fifth(&short_array).unwrap_or_else(|| {
+        println!("something went wrong while unwrapping");
+        0
+    });
+    println!("unwrapping or else, returning fallback: {}", number);
+}
+
+pub fn result_and_handling() {
+    fn divide(numerator: f64, denominator: f64) -> Result<f64, String> {
+        if denominator == 0.0 {
+            Err("het gaat fout".to_string())
+        } else {
+            Ok(numerator / denominator)
+        }
+    }
+
+    match divide(4.0, 0.0) {
+        Ok(_) => println!("it was ok"),
+        Err(e) => println!("it was an error {}", e),
+    }
+}
+
+pub fn traitbounds() {
+    fn print_only_stringlike<T>(input: T)
+    where
+        T: AsRef<str> + Display,
+    {
+        println!("{}", input);
+    }
+
+    print_only_stringlike("this is an &string");
+    print_only_stringlike("this is an String".to_string());
+    //print_only_stringlike(1); // -> AsRef<str> not satisfied
+}
+
+pub fn debug_logging() {
+    let number = 5;
+    dbg!(number);
+}
+
+pub fn mods() {
+    use crate::print_things::PrintThings;
+    PrintThings::prints_one_thing(6);
+    let my_struct = PrintThings::create(10);
+    PrintThings::prints_one_thing(&my_struct);
+    println!("custom displayed struct {}", my_struct);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn types_returns_char() {
+        let result = types();
+        assert_eq!(result, '=');
+    }
+
+    #[test]
+    fn mutability_returns_integer() {
+        let result = mutability();
+        assert_eq!(result, 1_000_035);
+    }
+}
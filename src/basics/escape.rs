@@ -0,0 +1,341 @@
+use std::fmt;
+use std::str::CharIndices;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Style {
+    Rust,
+    Json,
+    Html,
+}
+
+impl Style {
+    pub const ALL: [Style; 3] = [Style::Rust, Style::Json, Style::Html];
+
+    pub fn parse(name: &str) -> Option<Style> {
+        match name {
+            "rust" => Some(Style::Rust),
+            "json" => Some(Style::Json),
+            "html" => Some(Style::Html),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum EscapeError {
+    // `at` is the byte offset where the escape starts
+    Truncated { at: usize },
+    UnknownEscape { at: usize, escape: String },
+    LoneSurrogate { at: usize, code_point: u32 },
+    OutOfRange { at: usize, code_point: u32 },
+}
+
+impl fmt::Display for EscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EscapeError::Truncated { at } => write!(f, "escape at {at} is cut off"),
+            EscapeError::UnknownEscape { at, escape } => {
+                write!(f, "unknown escape {escape:?} at {at}")
+            }
+            EscapeError::LoneSurrogate { at, code_point } => {
+                write!(f, "lone surrogate U+{code_point:04X} at {at}")
+            }
+            EscapeError::OutOfRange { at, code_point } => {
+                write!(f, "U+{code_point:X} at {at} is not a unicode code point")
+            }
+        }
+    }
+}
+
+// surrogates are reserved for utf-16 pairs, they never stand for a char themselves
+fn code_point(at: usize, value: u32) -> Result<char, EscapeError> {
+    match value {
+        0xD800..=0xDFFF => Err(EscapeError::LoneSurrogate {
+            at,
+            code_point: value,
+        }),
+        _ => char::from_u32(value).ok_or(EscapeError::OutOfRange {
+            at,
+            code_point: value,
+        }),
+    }
+}
+
+pub fn escape(text: &str, style: Style) -> String {
+    let mut escaped = String::new();
+    for char in text.chars() {
+        match (style, char) {
+            (Style::Html, '&') => escaped.push_str("&amp;"),
+            (Style::Html, '<') => escaped.push_str("&lt;"),
+            (Style::Html, '>') => escaped.push_str("&gt;"),
+            (Style::Html, '"') => escaped.push_str("&quot;"),
+            (Style::Html, '\'') => escaped.push_str("&#39;"),
+            (Style::Html, char) if char.is_ascii() => escaped.push(char),
+            (Style::Html, char) => escaped.push_str(&format!("&#x{:X};", char as u32)),
+
+            (_, '\\') => escaped.push_str("\\\\"),
+            (_, '"') => escaped.push_str("\\\""),
+            (_, '\n') => escaped.push_str("\\n"),
+            (_, '\r') => escaped.push_str("\\r"),
+            (_, '\t') => escaped.push_str("\\t"),
+            (_, ' '..='~') => escaped.push(char),
+
+            (Style::Rust, '\0') => escaped.push_str("\\0"),
+            (Style::Rust, char) => escaped.push_str(&format!("\\u{{{:x}}}", char as u32)),
+            // json only has 4 hex digits, anything above needs a utf-16 surrogate pair
+            (Style::Json, char) => {
+                let mut units = [0; 2];
+                for unit in char.encode_utf16(&mut units) {
+                    escaped.push_str(&format!("\\u{unit:04x}"));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+type Chars<'a> = CharIndices<'a>;
+
+// exactly `count` hex digits
+fn hex_digits(chars: &mut Chars, count: usize, at: usize) -> Result<u32, EscapeError> {
+    let mut value = 0;
+    for _ in 0..count {
+        let (_, char) = chars.next().ok_or(EscapeError::Truncated { at })?;
+        let digit = char.to_digit(16).ok_or(EscapeError::UnknownEscape {
+            at,
+            escape: char.to_string(),
+        })?;
+        value = value * 16 + digit;
+    }
+    Ok(value)
+}
+
+fn unescape_rust(kind: char, chars: &mut Chars, at: usize) -> Result<char, EscapeError> {
+    match kind {
+        'n' => Ok('\n'),
+        'r' => Ok('\r'),
+        't' => Ok('\t'),
+        '0' => Ok('\0'),
+        '\\' | '"' | '\'' => Ok(kind),
+        // \x only reaches ascii, everything above needs \u
+        'x' => match hex_digits(chars, 2, at)? {
+            value @ 0..=0x7F => code_point(at, value),
+            value => Err(EscapeError::OutOfRange {
+                at,
+                code_point: value,
+            }),
+        },
+        'u' => {
+            if chars.next().map(|(_, char)| char) != Some('{') {
+                return Err(EscapeError::Truncated { at });
+            }
+            let mut digits = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, '}')) => break,
+                    Some((_, char)) if char.is_ascii_hexdigit() && digits.len() < 6 => {
+                        digits.push(char)
+                    }
+                    Some(_) => {
+                        return Err(EscapeError::UnknownEscape {
+                            at,
+                            escape: format!("\\u{{{digits}"),
+                        })
+                    }
+                    None => return Err(EscapeError::Truncated { at }),
+                }
+            }
+            // only empty braces fail to parse, six digits always fit
+            let value =
+                u32::from_str_radix(&digits, 16).map_err(|_| EscapeError::UnknownEscape {
+                    at,
+                    escape: "\\u{}".to_string(),
+                })?;
+            code_point(at, value)
+        }
+        kind => Err(EscapeError::UnknownEscape {
+            at,
+            escape: format!("\\{kind}"),
+        }),
+    }
+}
+
+fn unescape_json(kind: char, chars: &mut Chars, at: usize) -> Result<char, EscapeError> {
+    match kind {
+        'n' => Ok('\n'),
+        'r' => Ok('\r'),
+        't' => Ok('\t'),
+        'b' => Ok('\u{8}'),
+        'f' => Ok('\u{c}'),
+        '\\' | '"' | '/' => Ok(kind),
+        'u' => {
+            let high = hex_digits(chars, 4, at)?;
+            if !(0xD800..=0xDBFF).contains(&high) {
+                return code_point(at, high);
+            }
+            // a high surrogate only means something together with the low one after it
+            let lone = EscapeError::LoneSurrogate {
+                at,
+                code_point: high,
+            };
+            if chars.next().map(|(_, char)| char) != Some('\\')
+                || chars.next().map(|(_, char)| char) != Some('u')
+            {
+                return Err(lone);
+            }
+            let low = hex_digits(chars, 4, at)?;
+            if !(0xDC00..=0xDFFF).contains(&low) {
+                return Err(lone);
+            }
+            code_point(at, 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+        }
+        kind => Err(EscapeError::UnknownEscape {
+            at,
+            escape: format!("\\{kind}"),
+        }),
+    }
+}
+
+// what sits between '&' and ';' in a reference: a name, "#" and digits or "#x" and hex digits
+fn is_reference(name: &str) -> bool {
+    let (digits, radix) = match name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+        Some(hex) => (hex, 16),
+        None => match name.strip_prefix('#') {
+            Some(decimal) => (decimal, 10),
+            None => {
+                return !name.is_empty() && name.chars().all(|char| char.is_ascii_alphanumeric())
+            }
+        },
+    };
+    !digits.is_empty() && digits.chars().all(|char| char.is_digit(radix))
+}
+
+// named and numeric character references, "&#x1F44D;" is long enough for any code point;
+// like in browsers a '&' that doesn't start a reference is just a '&'
+fn unescape_html(chars: &mut Chars, at: usize) -> Result<char, EscapeError> {
+    // look ahead on a copy, so a bare '&' leaves the text after it alone
+    let mut ahead = chars.clone();
+    let mut name = String::new();
+    loop {
+        match ahead.next() {
+            Some((_, ';')) => break,
+            Some((_, char)) if name.len() < 10 => name.push(char),
+            Some(_) | None => return Ok('&'),
+        }
+    }
+    if !is_reference(&name) {
+        return Ok('&');
+    }
+    *chars = ahead;
+    let unknown = || EscapeError::UnknownEscape {
+        at,
+        escape: format!("&{name};"),
+    };
+    let value = match name.as_str() {
+        "amp" => return Ok('&'),
+        "lt" => return Ok('<'),
+        "gt" => return Ok('>'),
+        "quot" => return Ok('"'),
+        "apos" => return Ok('\''),
+        number => match number.strip_prefix("#x").or(number.strip_prefix("#X")) {
+            Some(hex) => u32::from_str_radix(hex, 16).map_err(|_| unknown())?,
+            None => match number.strip_prefix('#') {
+                Some(decimal) => decimal.parse::<u32>().map_err(|_| unknown())?,
+                None => return Err(unknown()),
+            },
+        },
+    };
+    code_point(at, value)
+}
+
+pub fn unescape(text: &str, style: Style) -> Result<String, EscapeError> {
+    let mut unescaped = String::new();
+    let mut chars = text.char_indices();
+    while let Some((at, char)) = chars.next() {
+        let char = match (style, char) {
+            (Style::Html, '&') => unescape_html(&mut chars, at)?,
+            (Style::Rust | Style::Json, '\\') => {
+                let (_, kind) = chars.next().ok_or(EscapeError::Truncated { at })?;
+                match style {
+                    Style::Rust => unescape_rust(kind, &mut chars, at)?,
+                    _ => unescape_json(kind, &mut chars, at)?,
+                }
+            }
+            (_, char) => char,
+        };
+        unescaped.push(char);
+    }
+    Ok(unescaped)
+}
+
+pub fn escapes() {
+    let text = "thumbs \"👍\" <ö>\n";
+    for style in Style::ALL {
+        let escaped = escape(text, style);
+        println!("{style:?}: {escaped}");
+        println!("  and back: {:?}", unescape(&escaped, style));
+    }
+    for (style, broken) in [
+        (Style::Json, r"\ud83d!"),
+        (Style::Rust, r"\u{110000}"),
+        (Style::Html, "&#xDC00;"),
+    ] {
+        println!(
+            "{style:?} {broken}: {}",
+            unescape(broken, style).unwrap_err()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_style_round_trips() {
+        let text = "thumbs \"👍\" <ö> & 'e\u{301}'\n\t\\ \0 \u{7f}";
+        for style in Style::ALL {
+            assert_eq!(unescape(&escape(text, style), style).unwrap(), text);
+        }
+        assert_eq!(escape("👍", Style::Rust), r"\u{1f44d}");
+        assert_eq!(escape("👍", Style::Json), r"\ud83d\udc4d");
+        assert_eq!(escape("👍", Style::Html), "&#x1F44D;");
+        assert_eq!(unescape("&#128077;", Style::Html).unwrap(), "👍");
+    }
+
+    #[test]
+    fn bare_ampersands_pass_through_html() {
+        for text in ["a & b", "AT&T", "fish & chips;", "&", "&;", "&#;", "&#xg;"] {
+            assert_eq!(unescape(text, Style::Html).unwrap(), text);
+        }
+        assert_eq!(unescape("&&amp;&", Style::Html).unwrap(), "&&&");
+        assert!(matches!(
+            unescape("&nbsp;", Style::Html),
+            Err(EscapeError::UnknownEscape { at: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn surrogates_and_out_of_range_are_errors() {
+        let lone = |at, code_point| Err(EscapeError::LoneSurrogate { at, code_point });
+        assert_eq!(unescape(r"a\ud83d", Style::Json), lone(1, 0xD83D));
+        assert_eq!(unescape(r"\ud83dA", Style::Json), lone(0, 0xD83D));
+        assert_eq!(unescape(r"\udc4d", Style::Json), lone(0, 0xDC4D));
+        assert_eq!(unescape(r"\u{d800}", Style::Rust), lone(0, 0xD800));
+        assert_eq!(unescape("&#xD800;", Style::Html), lone(0, 0xD800));
+
+        let out_of_range = |code_point| Err(EscapeError::OutOfRange { at: 0, code_point });
+        assert_eq!(unescape(r"\u{110000}", Style::Rust), out_of_range(0x110000));
+        assert_eq!(unescape(r"\x80", Style::Rust), out_of_range(0x80));
+        assert_eq!(unescape("&#1114112;", Style::Html), out_of_range(0x110000));
+
+        assert_eq!(
+            unescape(r"\u{1f44d", Style::Rust),
+            Err(EscapeError::Truncated { at: 0 })
+        );
+        assert!(matches!(
+            unescape(r"\q", Style::Json),
+            Err(EscapeError::UnknownEscape { .. })
+        ));
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt;
+use std::io::{self, Read, Write};
+
+// the same defaults as xxd: 16 bytes per line, in groups of 2
+pub struct Layout {
+    columns: usize,
+    group: usize,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout {
+            columns: 16,
+            group: 2,
+        }
+    }
+}
+
+impl Layout {
+    // `columns` bytes per line, a space after every `group` of them (0 for none);
+    // a line without columns can't hold a byte, so that layout is None
+    pub fn new(columns: usize, group: usize) -> Option<Layout> {
+        (columns > 0).then_some(Layout { columns, group })
+    }
+
+    // width of a full line of hex, so the ascii gutter lines up on the last line too
+    fn hex_width(&self) -> usize {
+        match self.group {
+            0 => self.columns * 2,
+            group => self.columns * 2 + (self.columns - 1) / group,
+        }
+    }
+}
+
+pub fn format_line(offset: usize, bytes: &[u8], layout: &Layout) -> String {
+    let mut hex = String::new();
+    for (index, byte) in bytes.iter().enumerate() {
+        if index > 0 && layout.group > 0 && index % layout.group == 0 {
+            hex.push(' ');
+        }
+        hex.push_str(&format!("{byte:02x}"));
+    }
+    let ascii = bytes
+        .iter()
+        .map(|&byte| match byte {
+            b' ' => ' ',
+            byte if byte.is_ascii_graphic() => byte as char,
+            _ => '.',
+        })
+        .collect::<String>();
+    format!(
+        "{offset:08x}: {hex:<width$}  {ascii}",
+        width = layout.hex_width()
+    )
+}
+
+// streams the input a line at a time, so large files are never loaded whole
+pub fn write_dump(
+    mut reader: impl Read,
+    mut writer: impl Write,
+    layout: &Layout,
+) -> io::Result<()> {
+    let mut buffer = vec![0; layout.columns];
+    let mut offset = 0;
+    loop {
+        // a read can return fewer bytes than asked for, keep going until the line is full
+        let mut filled = 0;
+        while filled < buffer.len() {
+            match reader.read(&mut buffer[filled..]) {
+                Ok(0) => break,
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+        if filled == 0 {
+            return Ok(());
+        }
+        writeln!(writer, "{}", format_line(offset, &buffer[..filled], layout))?;
+        offset += filled;
+    }
+}
+
+pub fn dump(bytes: &[u8], layout: &Layout) -> String {
+    let mut output = vec![];
+    write_dump(bytes, &mut output, layout).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReverseError {
+    MissingOffset { line: usize },
+    InvalidOffset { line: usize },
+    InvalidHex { line: usize, digit: char },
+    OddDigits { line: usize },
+    GapTooLarge { line: usize, offset: usize },
+}
+
+impl fmt::Display for ReverseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReverseError::MissingOffset { line } => write!(f, "line {line}: no offset"),
+            ReverseError::InvalidOffset { line } => write!(f, "line {line}: invalid offset"),
+            ReverseError::InvalidHex { line, digit } => {
+                write!(f, "line {line}: {digit:?} is not a hex digit")
+            }
+            ReverseError::OddDigits { line } => write!(f, "line {line}: odd number of hex digits"),
+            ReverseError::GapTooLarge { line, offset } => {
+                write!(
+                    f,
+                    "line {line}: offset {offset:#x} skips more than {MAX_GAP} bytes"
+                )
+            }
+        }
+    }
+}
+
+// the most zeros a jump in offsets may fill in, so a typo like ffffffffff doesn't
+// try to allocate a terabyte
+pub const MAX_GAP: usize = 1 << 20;
+
+// turns a dump back into bytes, the ascii gutter is ignored
+pub fn reverse(dump: &str) -> Result<Vec<u8>, ReverseError> {
+    let mut bytes = vec![];
+    for (index, text) in dump.lines().enumerate() {
+        let line = index + 1;
+        if text.trim().is_empty() {
+            continue;
+        }
+        let (offset, rest) = text
+            .split_once(": ")
+            .ok_or(ReverseError::MissingOffset { line })?;
+        let offset = usize::from_str_radix(offset.trim(), 16)
+            .map_err(|_| ReverseError::InvalidOffset { line })?;
+        // the hex ends at the two spaces in front of the gutter
+        let hex = rest.split("  ").next().unwrap_or_default();
+        let digits = hex
+            .chars()
+            .filter(|char| *char != ' ')
+            .collect::<Vec<char>>();
+        if let Some(&digit) = digits.iter().find(|char| !char.is_ascii_hexdigit()) {
+            return Err(ReverseError::InvalidHex { line, digit });
+        }
+        if digits.len() % 2 != 0 {
+            return Err(ReverseError::OddDigits { line });
+        }
+
+        // like xxd -r, a gap between offsets is filled with zeros
+        if offset.saturating_sub(bytes.len()) > MAX_GAP {
+            return Err(ReverseError::GapTooLarge { line, offset });
+        }
+        if bytes.len() < offset {
+            bytes.resize(offset, 0);
+        }
+        for (position, pair) in (offset..).zip(digits.chunks(2)) {
+            let byte = (pair[0].to_digit(16).unwrap() * 16 + pair[1].to_digit(16).unwrap()) as u8;
+            match bytes.get_mut(position) {
+                Some(existing) => *existing = byte,
+                None => bytes.push(byte),
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+pub fn hexdump() {
+    let data = "hellö wörld, 👍\n\tbye".as_bytes();
+    let dumped = dump(data, &Layout::default());
+    print!("{dumped}");
+    let narrow = Layout::new(8, 4).unwrap();
+    print!("{}", dump(data, &narrow));
+    let reversed = reverse(&dumped).unwrap();
+    println!("reversed: {:?}", String::from_utf8_lossy(&reversed));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dumps_like_xxd() {
+        let dumped = dump(b"hello world, this is xxd\n", &Layout::default());
+        assert_eq!(
+            dumped,
+            "00000000: 6865 6c6c 6f20 776f 726c 642c 2074 6869  hello world, thi\n\
+             00000010: 7320 6973 2078 7864 0a                   s is xxd.\n"
+        );
+    }
+
+    #[test]
+    fn every_byte_round_trips() {
+        let bytes = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        for layout in [
+            Layout::default(),
+            Layout::new(8, 4).unwrap(),
+            Layout::new(7, 0).unwrap(),
+        ] {
+            assert_eq!(reverse(&dump(&bytes, &layout)).unwrap(), bytes);
+        }
+        assert!(Layout::new(0, 2).is_none());
+    }
+
+    #[test]
+    fn reverse_reports_the_broken_line() {
+        assert_eq!(
+            reverse("00000000: 6869\n6869"),
+            Err(ReverseError::MissingOffset { line: 2 })
+        );
+        assert_eq!(
+            reverse("00000000: 6g69"),
+            Err(ReverseError::InvalidHex {
+                line: 1,
+                digit: 'g'
+            })
+        );
+        assert_eq!(
+            reverse("00000000: 686"),
+            Err(ReverseError::OddDigits { line: 1 })
+        );
+        assert_eq!(reverse("00000004: 68").unwrap(), vec![0, 0, 0, 0, 0x68]);
+        assert_eq!(
+            reverse("00000000: 6869\nffffffffff: 00"),
+            Err(ReverseError::GapTooLarge {
+                line: 2,
+                offset: 0xffffffffff
+            })
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use syn::{LitByteStr, LitStr};
+
+// the same text written as three kinds of rust literal
+#[derive(Debug, PartialEq)]
+pub struct Literals {
+    pub normal: String,
+    pub raw: Option<String>,
+    pub byte_string: String,
+}
+
+// escape_debug only escapes what a "..." literal cannot hold as is
+pub fn normal(text: &str) -> String {
+    format!("\"{}\"", text.escape_debug())
+}
+
+// the lexer counts the #s of a raw string delimiter in a u8
+pub const MAX_HASHES: usize = 255;
+
+// a raw string ends at the first quote followed by as many #s as it started with,
+// so it needs one more # than the longest run of #s after any quote in the text
+pub fn raw(text: &str) -> Option<String> {
+    // the lexer refuses a bare carriage return in a raw string, and nothing can escape it
+    if text.contains('\r') {
+        return None;
+    }
+    let hashes = text
+        .split('"')
+        .skip(1)
+        .map(|after_quote| after_quote.chars().take_while(|char| *char == '#').count() + 1)
+        .max()
+        .unwrap_or(0);
+    if hashes > MAX_HASHES {
+        return None;
+    }
+    let hashes = "#".repeat(hashes);
+    Some(format!("r{hashes}\"{text}\"{hashes}"))
+}
+
+// byte strings only hold ascii, every other byte of the utf-8 becomes \x..
+pub fn byte_string(text: &str) -> String {
+    let escaped = text
+        .bytes()
+        .flat_map(std::ascii::escape_default)
+        .map(char::from)
+        .collect::<String>();
+    format!("b\"{escaped}\"")
+}
+
+pub fn literals(text: &str) -> Literals {
+    Literals {
+        normal: normal(text),
+        raw: raw(text),
+        byte_string: byte_string(text),
+    }
+}
+
+// syn tokenizes the literal with the same rules as rustc, then we compare its value
+pub fn parse_str(literal: &str) -> Result<String, String> {
+    syn::parse_str::<LitStr>(literal)
+        .map(|literal| literal.value())
+        .map_err(|error| error.to_string())
+}
+
+pub fn parse_byte_string(literal: &str) -> Result<Vec<u8>, String> {
+    syn::parse_str::<LitByteStr>(literal)
+        .map(|literal| literal.value())
+        .map_err(|error| error.to_string())
+}
+
+pub fn round_trips(text: &str, literals: &Literals) -> Result<(), String> {
+    let mut strings = vec![&literals.normal];
+    strings.extend(&literals.raw);
+    for literal in strings {
+        if parse_str(literal)? != text {
+            return Err(format!("{literal} does not hold the original text"));
+        }
+    }
+    if parse_byte_string(&literals.byte_string)? != text.as_bytes() {
+        return Err(format!(
+            "{} does not hold the original bytes",
+            literals.byte_string
+        ));
+    }
+    Ok(())
+}
+
+pub fn print_literals(text: &str) {
+    let literals = literals(text);
+    println!("{text:?}");
+    println!("  normal: {}", literals.normal);
+    match &literals.raw {
+        Some(raw) => println!("  raw:    {raw}"),
+        None if text.contains('\r') => {
+            println!("  raw:    impossible, raw strings cannot hold a \\r")
+        }
+        None => println!("  raw:    impossible, it would need more than {MAX_HASHES} #s"),
+    }
+    println!("  bytes:  {}", literals.byte_string);
+    println!("  round trips: {:?}", round_trips(text, &literals));
+}
+
+pub fn string_literals() {
+    for text in [
+        r#"313213j123 "bla" // \n \t jlslkjdsf sdf kjdsflkj"#,
+        "a \"# and a \"## inside",
+        "tab\tthumbs 👍 e\u{301}",
+        "carriage\r\nreturn",
+    ] {
+        print_literals(text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_strings_use_the_fewest_hashes() {
+        assert_eq!(raw("plain").unwrap(), r#"r"plain""#);
+        assert_eq!(raw(r#"say "hi""#).unwrap(), r##"r#"say "hi""#"##);
+        assert_eq!(raw(r##"a "# b"##).unwrap(), r###"r##"a "# b"##"###);
+        assert_eq!(raw("a\r\n"), None);
+        let most = format!("\"{}", "#".repeat(MAX_HASHES - 1));
+        assert_eq!(round_trips(&most, &literals(&most)), Ok(()));
+        assert_eq!(raw(&format!("\"{}", "#".repeat(MAX_HASHES))), None);
+
+        // one # less and the literal ends early
+        let text = r##"a "# b"##;
+        assert!(parse_str(&format!(r##"r#"{text}"#"##)).is_err());
+    }
+
+    #[test]
+    fn every_literal_round_trips() {
+        for text in [
+            "",
+            "plain",
+            r#"313213j123 "bla" // \n \t jlslkjdsf sdf kjdsflkj"#,
+            "quotes \" and \"## and \\ backslashes",
+            "\u{301} combining first, then 👨\u{200d}👩\u{200d}👧",
+            "control \0 \u{7f} \u{1b}[0m and \r\n",
+        ] {
+            let literals = literals(text);
+            assert_eq!(round_trips(text, &literals), Ok(()), "{literals:?}");
+        }
+    }
+}
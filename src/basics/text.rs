@@ -0,0 +1,122 @@
+use std::fmt;
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub struct CharInfo {
+    pub char: char,
+    pub code_point: u32,
+    pub utf8: Vec<u8>,
+    pub category: GeneralCategory,
+}
+
+impl CharInfo {
+    pub fn new(char: char) -> Self {
+        let mut buffer = [0; 4];
+        CharInfo {
+            char,
+            code_point: char as u32,
+            utf8: char.encode_utf8(&mut buffer).as_bytes().to_vec(),
+            category: get_general_category(char),
+        }
+    }
+}
+
+impl fmt::Display for CharInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let utf8 = self
+            .utf8
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect::<Vec<String>>()
+            .join(" ");
+        // show marks and invisible chars by their escape rather than printing them raw
+        let shown = match self.category.abbreviation() {
+            "Mn" | "Me" | "Cf" | "Cc" | "Zs" => self.char.escape_unicode().to_string(),
+            _ => self.char.to_string(),
+        };
+        write!(
+            f,
+            "U+{:04X} {utf8:<12} {} {shown}",
+            self.code_point,
+            self.category.abbreviation()
+        )
+    }
+}
+
+// a "character" can mean four different numbers, all of them right
+pub struct TextStats {
+    pub bytes: usize,
+    pub scalars: usize,
+    pub graphemes: usize,
+    pub width: usize,
+    pub chars: Vec<CharInfo>,
+}
+
+pub fn inspect(text: &str) -> TextStats {
+    TextStats {
+        bytes: text.len(),
+        scalars: text.chars().count(),
+        graphemes: text.graphemes(true).count(),
+        width: text.width(),
+        chars: text.chars().map(CharInfo::new).collect(),
+    }
+}
+
+impl fmt::Display for TextStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} bytes, {} scalars, {} graphemes, {} columns wide",
+            self.bytes, self.scalars, self.graphemes, self.width
+        )?;
+        for char in &self.chars {
+            writeln!(f, "  {char}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn text_statistics() {
+    for text in [
+        "hellö world",
+        "hello\u{308} world",
+        "👨\u{200d}👩\u{200d}👧",
+        "🇳🇱",
+        "日本",
+    ] {
+        println!("{text}");
+        print!("{}", inspect(text));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combining_marks_join_the_previous_letter() {
+        let composed = inspect("hellö");
+        let decomposed = inspect("hello\u{308}");
+        assert_eq!((composed.bytes, composed.scalars), (6, 5));
+        assert_eq!((decomposed.bytes, decomposed.scalars), (7, 6));
+        assert_eq!(decomposed.graphemes, composed.graphemes);
+        assert_eq!(decomposed.width, composed.width);
+        assert_eq!(
+            decomposed.chars[5].category,
+            GeneralCategory::NonspacingMark
+        );
+        assert_eq!(decomposed.chars[5].utf8, vec![0xCC, 0x88]);
+    }
+
+    #[test]
+    fn zwj_sequence_is_one_grapheme() {
+        let family = inspect("👨\u{200d}👩\u{200d}👧");
+        assert_eq!(family.bytes, 18);
+        assert_eq!(family.scalars, 5);
+        assert_eq!(family.graphemes, 1);
+        assert_eq!(family.width, 2);
+        assert_eq!(family.chars[1].category, GeneralCategory::Format);
+        assert_eq!(family.chars[0].code_point, 0x1F468);
+    }
+}
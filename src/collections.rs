@@ -0,0 +1,35 @@
+use std::collections::VecDeque;
+
+pub mod growth;
+pub mod ring;
+
+pub fn vectors_and_tuples() {
+    let vector_from_array: Vec<&str> = ["bluebox", "micromonsta", "se02"].into();
+    println!("length of vector: {}", vector_from_array.len());
+
+    let mut vector_of_tuples = vec![
+        ("synth", "micromonsta"),
+        ("controller", "hapax"),
+        ("mixer", "bluebox"),
+    ];
+    println!(
+        "capacity of the tuplevector is: {}",
+        vector_of_tuples.capacity()
+    );
+    vector_of_tuples.push(("controller", "launchpad"));
+    println!(
+        "after pushing 1, capacity tuplevector is: {}",
+        vector_of_tuples.capacity()
+    );
+
+    let (a, b) = vector_of_tuples[0];
+    println!("deconstructed tuple into {} {}", a, b);
+}
+
+pub fn vecdeq() {
+    let mut vecdeq = VecDeque::from(vec![0, 1, 2]);
+    let item = vecdeq.pop_front().unwrap();
+    vecdeq.push_front(5);
+    println!("{:?}", item);
+    println!("{:?}", vecdeq);
+}
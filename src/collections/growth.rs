@@ -0,0 +1,165 @@
+// a capacity change, `moved` tells whether the elements were copied to a new address
+#[derive(Debug, PartialEq)]
+pub struct Reallocation {
+    pub at_push: usize,
+    pub from: usize,
+    pub to: usize,
+    pub moved: bool,
+}
+
+pub struct Growth {
+    pub name: &'static str,
+    // capacity after every push, the len after push i is i + 1
+    pub capacities: Vec<usize>,
+    pub reallocations: Vec<Reallocation>,
+}
+
+// compares the capacity and address from before a change with the ones after it
+fn changed(at_push: usize, before: (usize, *const u64), vector: &Vec<u64>) -> Option<Reallocation> {
+    (vector.capacity() != before.0).then(|| Reallocation {
+        at_push,
+        from: before.0,
+        to: vector.capacity(),
+        moved: before.1 != vector.as_ptr(),
+    })
+}
+
+// pushes `pushes` numbers onto the vec setup returns and watches the capacity after each,
+// finish gets the full vec, e.g. to shrink it
+pub fn record(
+    name: &'static str,
+    pushes: usize,
+    setup: impl FnOnce() -> Vec<u64>,
+    finish: impl FnOnce(&mut Vec<u64>),
+) -> Growth {
+    let mut vector = setup();
+    let mut growth = Growth {
+        name,
+        capacities: vec![],
+        reallocations: vec![],
+    };
+    for push in 1..=pushes {
+        let before = (vector.capacity(), vector.as_ptr());
+        vector.push(push as u64);
+        growth.reallocations.extend(changed(push, before, &vector));
+        growth.capacities.push(vector.capacity());
+    }
+    let before = (vector.capacity(), vector.as_ptr());
+    finish(&mut vector);
+    growth
+        .reallocations
+        .extend(changed(pushes, before, &vector));
+    growth
+}
+
+// clippy would rather see with_capacity than reserve on a new vec, but here both are the point
+#[allow(clippy::reserve_after_initialization)]
+pub fn strategies(pushes: usize) -> Vec<Growth> {
+    vec![
+        record("Vec::new", pushes, Vec::new, |_| {}),
+        record(
+            "with_capacity",
+            pushes,
+            || Vec::with_capacity(pushes),
+            |_| {},
+        ),
+        record(
+            "reserve half",
+            pushes,
+            || {
+                let mut vector = Vec::new();
+                vector.reserve(pushes / 2);
+                vector
+            },
+            |_| {},
+        ),
+        record("shrink_to_fit", pushes, Vec::new, |vector| {
+            vector.shrink_to_fit()
+        }),
+    ]
+}
+
+// '#' is capacity holding elements, '.' is capacity that is allocated but unused
+pub fn chart(growth: &Growth, width: usize, height: usize) -> String {
+    let pushes = growth.capacities.len();
+    let highest = growth.capacities.iter().copied().max().unwrap_or(0).max(1);
+    // one column per push, or a sample of them when there are more pushes than columns
+    let columns = (0..width.min(pushes))
+        .map(|column| (column + 1) * pushes / width.min(pushes) - 1)
+        .collect::<Vec<usize>>();
+
+    let mut lines = vec![];
+    for row in (1..=height).rev() {
+        let level = row * highest / height;
+        let line = columns
+            .iter()
+            .map(|&push| {
+                if push + 1 >= level {
+                    '#'
+                } else if growth.capacities[push] >= level {
+                    '.'
+                } else {
+                    ' '
+                }
+            })
+            .collect::<String>();
+        lines.push(format!("{level:>6} |{line}"));
+    }
+    lines.push(format!("{:>6} +{}", 0, "-".repeat(columns.len())));
+    lines.push(format!("{:>8}{pushes} pushes", ""));
+    lines.join("\n")
+}
+
+pub fn vec_growth() {
+    for growth in strategies(100) {
+        println!("{}", growth.name);
+        for reallocation in &growth.reallocations {
+            println!(
+                "  push {:>3}: capacity {:>3} -> {:>3}{}",
+                reallocation.at_push,
+                reallocation.from,
+                reallocation.to,
+                if reallocation.moved { ", moved" } else { "" }
+            );
+        }
+        println!("{}", chart(&growth, 50, 8));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strategies_trade_reallocations_for_unused_capacity() {
+        let [new, with_capacity, reserve, shrink] = strategies(100).try_into().ok().unwrap();
+
+        assert!(new.reallocations.len() > 3);
+        // amortized growth: every reallocation at least doubles the capacity
+        assert!(new.reallocations[1..]
+            .iter()
+            .all(|reallocation| reallocation.to >= reallocation.from * 2));
+
+        assert!(with_capacity.reallocations.is_empty());
+        assert_eq!(with_capacity.capacities.last(), Some(&100));
+
+        assert_eq!(reserve.capacities[49], 50);
+        assert_eq!(reserve.reallocations.len(), 1);
+        assert_eq!(reserve.reallocations[0].at_push, 51);
+
+        let last = shrink.reallocations.last().unwrap();
+        assert!(last.from > 100);
+        assert_eq!(last.to, 100);
+    }
+
+    #[test]
+    fn chart_fills_up_to_len_and_dots_the_spare_capacity() {
+        let growth = record("tiny", 4, || Vec::with_capacity(4), |_| {});
+        assert_eq!(
+            chart(&growth, 4, 2),
+            "     4 |...#\n     2 |.###\n     0 +----\n        4 pushes"
+        );
+        let growth = record("spare", 2, || Vec::with_capacity(4), |_| {});
+        assert_eq!(chart(&growth, 2, 2).lines().next(), Some("     4 |.."));
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::VecDeque;
+
+use crate::iterators::{Measurement, MEASUREMENT_LOGS};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WhenFull {
+    OverwriteOldest,
+    Reject,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Push<T> {
+    Stored,
+    Overwrote(T),
+    Rejected(T),
+}
+
+// a fixed number of slots, allocated once; `head` is the slot of the oldest value
+pub struct RingBuffer<T> {
+    slots: Box<[Option<T>]>,
+    head: usize,
+    len: usize,
+    when_full: WhenFull,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn new(capacity: usize, when_full: WhenFull) -> Self {
+        assert!(capacity > 0, "a ring buffer needs at least one slot");
+        RingBuffer {
+            slots: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+            when_full,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    // the slot `offset` places after the oldest value, wrapping around the end
+    fn slot(&self, offset: usize) -> usize {
+        (self.head + offset) % self.capacity()
+    }
+
+    pub fn push(&mut self, value: T) -> Push<T> {
+        if !self.is_full() {
+            let tail = self.slot(self.len);
+            self.slots[tail] = Some(value);
+            self.len += 1;
+            return Push::Stored;
+        }
+        match self.when_full {
+            WhenFull::Reject => Push::Rejected(value),
+            // when full the oldest slot is also the next free one, so the head moves on
+            WhenFull::OverwriteOldest => {
+                let oldest = self.slots[self.head].replace(value).unwrap();
+                self.head = self.slot(1);
+                Push::Overwrote(oldest)
+            }
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let oldest = self.slots[self.head].take();
+        self.head = self.slot(1);
+        self.len -= 1;
+        oldest
+    }
+
+    // oldest to newest
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            buffer: self,
+            offset: 0,
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    buffer: &'a RingBuffer<T>,
+    offset: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.offset == self.buffer.len {
+            return None;
+        }
+        let slot = self.buffer.slot(self.offset);
+        self.offset += 1;
+        self.buffer.slots[slot].as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.buffer.len - self.offset;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+pub struct IntoIter<T>(RingBuffer<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for RingBuffer<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+// average of the last `window` values at every step, the sum is kept up to date
+// instead of adding up the whole window again; an empty window has no average, so a
+// `window` of 0 gives an empty vec
+pub fn rolling_average(values: &[f32], window: usize) -> Vec<f32> {
+    if window == 0 {
+        return vec![];
+    }
+    let mut buffer = RingBuffer::new(window, WhenFull::OverwriteOldest);
+    let mut sum = 0.0;
+    values
+        .iter()
+        .map(|&value| {
+            sum += value;
+            if let Push::Overwrote(oldest) = buffer.push(value) {
+                sum -= oldest;
+            }
+            sum / buffer.len() as f32
+        })
+        .collect()
+}
+
+// the same with a VecDeque, which has to be kept from growing by hand
+pub fn rolling_average_vecdeque(values: &[f32], window: usize) -> Vec<f32> {
+    if window == 0 {
+        return vec![];
+    }
+    let mut buffer = VecDeque::with_capacity(window);
+    let mut sum = 0.0;
+    values
+        .iter()
+        .map(|&value| {
+            if buffer.len() == window {
+                sum -= buffer.pop_front().unwrap();
+            }
+            buffer.push_back(value);
+            sum += value;
+            sum / buffer.len() as f32
+        })
+        .collect()
+}
+
+pub fn ring_buffer() {
+    let mut recent = RingBuffer::new(3, WhenFull::OverwriteOldest);
+    let mut queue = RingBuffer::new(3, WhenFull::Reject);
+    for device in ["micromonsta", "hapax", "bluebox", "se02"] {
+        println!("overwrite: push {device} -> {:?}", recent.push(device));
+        println!("reject:    push {device} -> {:?}", queue.push(device));
+    }
+    println!("recent devices: {:?}", recent.iter().collect::<Vec<_>>());
+    println!(
+        "queued devices: {:?}",
+        queue.into_iter().collect::<Vec<_>>()
+    );
+
+    let temperatures = MEASUREMENT_LOGS
+        .iter()
+        .filter_map(|line| Measurement::new(line))
+        .map(|measurement| measurement.value)
+        .collect::<Vec<f32>>();
+    println!("temperatures: {temperatures:?}");
+    println!(
+        "rolling average over 2: {:?}",
+        rolling_average(&temperatures, 2)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_buffers_overwrite_or_reject() {
+        let mut overwrite = RingBuffer::new(2, WhenFull::OverwriteOldest);
+        let mut reject = RingBuffer::new(2, WhenFull::Reject);
+        for value in 1..=2 {
+            assert_eq!(overwrite.push(value), Push::Stored);
+            assert_eq!(reject.push(value), Push::Stored);
+        }
+        assert_eq!(overwrite.push(3), Push::Overwrote(1));
+        assert_eq!(reject.push(3), Push::Rejected(3));
+        assert_eq!(overwrite.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(reject.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+        // popping frees a slot, also once the head has wrapped around
+        assert_eq!(overwrite.pop(), Some(2));
+        assert_eq!(overwrite.push(4), Push::Stored);
+        assert_eq!(overwrite.iter().len(), 2);
+        assert_eq!(overwrite.into_iter().collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn rolling_averages_match_a_recomputed_window() {
+        let values = (0..50)
+            .map(|value| (value * 7 % 13) as f32)
+            .collect::<Vec<f32>>();
+        let expected = (0..values.len())
+            .map(|end| {
+                let window = &values[end.saturating_sub(4)..=end];
+                window.iter().sum::<f32>() / window.len() as f32
+            })
+            .collect::<Vec<f32>>();
+        assert_eq!(rolling_average(&values, 5), expected);
+        assert_eq!(rolling_average_vecdeque(&values, 5), expected);
+
+        assert!(rolling_average(&values, 0).is_empty());
+        assert!(rolling_average_vecdeque(&values, 0).is_empty());
+    }
+}
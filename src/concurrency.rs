@@ -0,0 +1,250 @@
+use std::sync::Mutex;
+
+// the counter and channel lessons are built on these, so building with --cfg loom
+// can run them on loom's model of threads and check every interleaving
+#[cfg(loom)]
+mod model {
+    pub use loom::sync::mpsc::channel;
+    pub use loom::sync::{Arc, Mutex};
+    pub use loom::thread;
+}
+#[cfg(not(loom))]
+mod model {
+    pub use std::sync::mpsc::channel;
+    pub use std::sync::{Arc, Mutex};
+    pub use std::thread;
+}
+
+pub mod contention;
+pub mod deadlock;
+pub mod pipeline;
+pub mod primitives;
+pub mod workers;
+
+// second_mut_changer is declared mut without being changed
+#[allow(unused_mut)]
+pub fn mutex() {
+    let my_mutex = Mutex::new(5);
+    let mut mutex_changer = my_mutex.lock().unwrap();
+    println!("{:?}", my_mutex);
+    println!("{:?}", mutex_changer);
+    *mutex_changer = 6;
+    println!("{:?}", mutex_changer);
+
+    let mut second_mut_changer = my_mutex.try_lock();
+    if let Ok(value) = &second_mut_changer {
+        dbg!(value);
+    } else {
+        dbg!("I didn't get the (second) lock");
+    };
+    std::mem::drop(mutex_changer);
+
+    // immediately change without variable
+    *my_mutex.lock().unwrap() = 7;
+    println!("{:?}", my_mutex);
+
+    *my_mutex.lock().unwrap() = 10;
+    println!("{:?}", my_mutex);
+}
+
+pub fn naive_counter() -> i32 {
+    use model::{thread, Arc, Mutex};
+    let my_number = Arc::new(Mutex::new(0));
+
+    let my_number1 = Arc::clone(&my_number);
+    let my_number2 = Arc::clone(&my_number);
+
+    let thread_one = thread::spawn(move || {
+        for i in 0..10 {
+            *my_number1.lock().unwrap() += 1;
+            println!("printing thread 1 iteration {}: {:?}", i, my_number1);
+        }
+    });
+
+    let thread_two = thread::spawn(move || {
+        for i in 0..10 {
+            *my_number2.lock().unwrap() += 1;
+            println!("printing thread 2 iteration {}: {:?}", i, my_number2);
+        }
+    });
+
+    thread_one.join().unwrap();
+    thread_two.join().unwrap();
+    let total = *my_number.lock().unwrap();
+    total
+}
+
+pub fn multithreading_naive() {
+    naive_counter();
+    println!("done multithreading_naive");
+}
+
+pub fn counter_with_handles() -> i32 {
+    use model::{thread, Arc, Mutex};
+    let my_number = Arc::new(Mutex::new(0));
+    let mut join_handle_vec = vec![];
+
+    for thread in 0..2 {
+        let my_number_clone = Arc::clone(&my_number);
+        let handle = thread::spawn(move || {
+            for i in 0..10 {
+                *my_number_clone.lock().unwrap() += 1;
+                println!(
+                    "printing thread {} iteration {}: {:?}",
+                    thread, i, my_number_clone
+                );
+            }
+        });
+        join_handle_vec.push(handle);
+    }
+
+    join_handle_vec.into_iter().for_each(|handle| {
+        handle.join().unwrap();
+    });
+    println!("{:?}", my_number);
+    let total = *my_number.lock().unwrap();
+    total
+}
+
+pub fn multithreading() {
+    counter_with_handles();
+    println!("done multithreading_naive");
+}
+
+pub fn channel_messages() -> Vec<&'static str> {
+    use model::{channel, thread};
+    let (sender, receiver) = channel();
+
+    let sender_clone = sender.clone();
+
+    let mut handlers = vec![];
+
+    let handle1 = thread::spawn(move || {
+        sender.send("Send a &str").unwrap();
+    });
+
+    let handle2 = thread::spawn(move || {
+        sender_clone.send("Send another &str").unwrap();
+    });
+
+    handlers.push(handle1);
+    handlers.push(handle2);
+
+    let mut messages = vec![];
+    for _ in handlers {
+        // still prints in random order, depending on which thread finishes first
+        let message = receiver.recv().unwrap();
+        println!("{:?}", message);
+        messages.push(message);
+    }
+    messages
+}
+
+pub fn channels() {
+    channel_messages();
+}
+
+// splits `total` zeroes over `threads` threads, increments them and collects the result
+pub fn big_multithreading_work(total: usize, threads: usize) -> Vec<u8> {
+    use std::sync::mpsc::channel;
+    use std::thread::spawn;
+
+    assert!(threads > 0, "the work needs at least one thread");
+    let per_thread = total / threads;
+
+    let (sender, receiver) = channel();
+    let big_vec = vec![0; total];
+    let mut result = vec![];
+    let mut handlers = vec![];
+
+    for i in 0..threads {
+        let sender_clone = sender.clone();
+        let start = i * per_thread;
+        // the last thread also takes what doesn't divide evenly
+        let end = if i == threads - 1 {
+            total
+        } else {
+            (i + 1) * per_thread
+        };
+        let mut work: Vec<u8> = Vec::with_capacity(end - start);
+        work.extend(&big_vec[start..end]);
+        let handle = spawn(move || {
+            for number in work.iter_mut() {
+                *number += 1;
+            }
+            sender_clone.send(work).unwrap();
+        });
+        handlers.push(handle);
+    }
+    drop(sender);
+
+    for handle in handlers {
+        handle.join().unwrap();
+    }
+
+    while let Ok(results) = receiver.try_recv() {
+        result.push(results);
+    }
+
+    result.into_iter().flatten().collect::<Vec<u8>>()
+}
+
+pub fn big_multithreading() {
+    const TOTAL: usize = 1_000_000;
+    const THREADS: usize = 10;
+
+    let flattened_result = big_multithreading_work(TOTAL, THREADS);
+
+    println!(
+        "big work result length: {}, range {:?}",
+        flattened_result.len(),
+        &flattened_result[1_000..1_100]
+    );
+}
+
+pub fn number_items(items: &mut [usize]) {
+    items
+        .iter_mut()
+        .enumerate()
+        .for_each(|(index, number)| *number += index + 1);
+}
+
+pub fn par_number_items(items: &mut [usize]) {
+    use rayon::prelude::*;
+    items
+        .par_iter_mut()
+        .enumerate()
+        .for_each(|(index, number)| *number += index + 1);
+}
+
+// a single timed run is only a rough indication, see benches/lessons.rs for proper numbers
+pub fn rayon() {
+    use std::time::Instant;
+    const TOTAL_ITEMS: usize = 1_200_000;
+
+    let mut my_vec = vec![0; TOTAL_ITEMS];
+    let before1 = Instant::now();
+    number_items(&mut my_vec);
+    println!(
+        "no rayon: {:?} {:?}",
+        before1.elapsed(),
+        &my_vec[5000..5005]
+    );
+
+    let mut my_vec = vec![0; TOTAL_ITEMS];
+    let before2 = Instant::now();
+    par_number_items(&mut my_vec);
+    println!("rayon: {:?} {:?}", before2.elapsed(), &my_vec[5000..5005]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn big_work_keeps_the_remainder() {
+        let result = big_multithreading_work(10, 3);
+        assert_eq!(result, vec![1; 10]);
+        assert_eq!(big_multithreading_work(2, 3), vec![1; 2]);
+    }
+}
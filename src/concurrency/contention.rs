@@ -0,0 +1,240 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicI32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+// a Mutex that keeps count of how often, how long and how contended it was locked
+#[derive(Debug)]
+pub struct InstrumentedMutex<T> {
+    name: &'static str,
+    inner: Mutex<T>,
+    acquisitions: AtomicU64,
+    contended: AtomicU64,
+    wait_nanos: AtomicU64,
+    hold_nanos: AtomicU64,
+}
+
+pub struct InstrumentedGuard<'a, T> {
+    mutex: &'a InstrumentedMutex<T>,
+    guard: MutexGuard<'a, T>,
+    acquired: Instant,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockStats {
+    pub name: &'static str,
+    pub acquisitions: u64,
+    // acquisitions that found the lock already taken and had to wait
+    pub contended: u64,
+    pub wait: Duration,
+    pub hold: Duration,
+}
+
+impl<T> InstrumentedMutex<T> {
+    pub fn new(name: &'static str, value: T) -> Self {
+        Self {
+            name,
+            inner: Mutex::new(value),
+            acquisitions: AtomicU64::new(0),
+            contended: AtomicU64::new(0),
+            wait_nanos: AtomicU64::new(0),
+            hold_nanos: AtomicU64::new(0),
+        }
+    }
+
+    // a poisoned lock is recovered, the counters are what this lesson is about
+    pub fn lock(&self) -> InstrumentedGuard<'_, T> {
+        let waiting = Instant::now();
+        let guard = match self.inner.try_lock() {
+            Ok(guard) => guard,
+            Err(_) => {
+                self.contended.fetch_add(1, Ordering::Relaxed);
+                self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+            }
+        };
+        self.acquisitions.fetch_add(1, Ordering::Relaxed);
+        self.wait_nanos
+            .fetch_add(waiting.elapsed().as_nanos() as u64, Ordering::Relaxed);
+        InstrumentedGuard {
+            mutex: self,
+            guard,
+            acquired: Instant::now(),
+        }
+    }
+
+    pub fn stats(&self) -> LockStats {
+        LockStats {
+            name: self.name,
+            acquisitions: self.acquisitions.load(Ordering::Relaxed),
+            contended: self.contended.load(Ordering::Relaxed),
+            wait: Duration::from_nanos(self.wait_nanos.load(Ordering::Relaxed)),
+            hold: Duration::from_nanos(self.hold_nanos.load(Ordering::Relaxed)),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Deref for InstrumentedGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for InstrumentedGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard
+    }
+}
+
+impl<T> Drop for InstrumentedGuard<'_, T> {
+    fn drop(&mut self) {
+        self.mutex
+            .hold_nanos
+            .fetch_add(self.acquired.elapsed().as_nanos() as u64, Ordering::Relaxed);
+    }
+}
+
+impl fmt::Display for LockStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let contention = if self.acquisitions == 0 {
+            0.0
+        } else {
+            self.contended as f64 / self.acquisitions as f64 * 100.0
+        };
+        write!(
+            f,
+            "{}: {} acquisitions, {:.1}% contended, waited {:?}, held {:?}",
+            self.name, self.acquisitions, contention, self.wait, self.hold
+        )
+    }
+}
+
+pub fn count_with_mutex(threads: usize, increments: usize) -> (i32, LockStats) {
+    let counter = Arc::new(InstrumentedMutex::new("mutex counter", 0));
+    let handles = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            std::thread::spawn(move || {
+                for _ in 0..increments {
+                    *counter.lock() += 1;
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let stats = counter.stats();
+    let counter = Arc::into_inner(counter).unwrap();
+    (counter.into_inner(), stats)
+}
+
+pub fn count_with_atomic(threads: usize, increments: usize) -> i32 {
+    let counter = Arc::new(AtomicI32::new(0));
+    let handles = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            std::thread::spawn(move || {
+                for _ in 0..increments {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    counter.load(Ordering::Relaxed)
+}
+
+// nothing is shared while counting, the totals are merged when joining
+pub fn count_with_local_counters(threads: usize, increments: usize) -> i32 {
+    let handles = (0..threads)
+        .map(|_| {
+            std::thread::spawn(move || {
+                let mut local = 0;
+                for _ in 0..increments {
+                    local += 1;
+                }
+                local
+            })
+        })
+        .collect::<Vec<_>>();
+    handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .sum()
+}
+
+pub fn lock_contention() {
+    const THREADS: usize = 4;
+    const INCREMENTS: usize = 100_000;
+
+    let before = Instant::now();
+    let (total, stats) = count_with_mutex(THREADS, INCREMENTS);
+    println!("Mutex<i32> counter: {total} in {:?}", before.elapsed());
+
+    let before = Instant::now();
+    let total = count_with_atomic(THREADS, INCREMENTS);
+    println!("AtomicI32 counter: {total} in {:?}", before.elapsed());
+
+    let before = Instant::now();
+    let total = count_with_local_counters(THREADS, INCREMENTS);
+    println!(
+        "local counters merged at join: {total} in {:?}",
+        before.elapsed()
+    );
+
+    println!("contention report:");
+    println!("  {stats}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_strategy_counts_every_increment() {
+        let (total, stats) = count_with_mutex(4, 1_000);
+        assert_eq!(total, 4_000);
+        assert_eq!(stats.acquisitions, 4_000);
+        assert!(stats.contended <= stats.acquisitions);
+        assert_eq!(count_with_atomic(4, 1_000), 4_000);
+        assert_eq!(count_with_local_counters(4, 1_000), 4_000);
+    }
+
+    #[test]
+    fn waiting_for_a_held_lock_is_recorded() {
+        let mutex = Arc::new(InstrumentedMutex::new("held", 0));
+        let guard = mutex.lock();
+        let about_to_lock = Arc::new(std::sync::Barrier::new(2));
+        let waiter = {
+            let mutex = Arc::clone(&mutex);
+            let about_to_lock = Arc::clone(&about_to_lock);
+            std::thread::spawn(move || {
+                about_to_lock.wait();
+                *mutex.lock() += 1
+            })
+        };
+        // only start the clock once the waiter is right in front of lock()
+        about_to_lock.wait();
+        std::thread::sleep(Duration::from_millis(20));
+        drop(guard);
+        waiter.join().unwrap();
+
+        let stats = mutex.stats();
+        assert_eq!(stats.acquisitions, 2);
+        assert_eq!(stats.contended, 1);
+        assert!(stats.wait >= Duration::from_millis(10));
+        assert!(stats.hold >= Duration::from_millis(10));
+    }
+}
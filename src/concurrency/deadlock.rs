@@ -0,0 +1,302 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier, Mutex, MutexGuard, PoisonError};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+// every "held -> acquired" pair seen so far, with the thread that first did it
+static LOCK_ORDER: Mutex<BTreeMap<(usize, usize), Acquisition>> = Mutex::new(BTreeMap::new());
+
+thread_local! {
+    static HELD: RefCell<Vec<(usize, &'static str)>> = const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Acquisition {
+    pub thread: String,
+    pub first: &'static str,
+    pub then: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeadlockError {
+    // this thread would take the locks in the opposite order of an earlier thread
+    Inversion {
+        earlier: Acquisition,
+        now: Acquisition,
+    },
+    // this thread already holds the lock it is asking for
+    Reentrant {
+        thread: String,
+        lock: &'static str,
+    },
+}
+
+impl fmt::Display for DeadlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeadlockError::Inversion { earlier, now } => write!(
+                f,
+                "lock order inversion: thread '{}' takes {} then {}, but thread '{}' took {} then {}",
+                now.thread, now.first, now.then, earlier.thread, earlier.first, earlier.then
+            ),
+            DeadlockError::Reentrant { thread, lock } => {
+                write!(f, "thread '{thread}' tried to lock {lock} twice")
+            }
+        }
+    }
+}
+
+impl Error for DeadlockError {}
+
+// a Mutex that, in debug builds, refuses to be locked in an order that can deadlock
+#[derive(Debug)]
+pub struct OrderedMutex<T> {
+    id: usize,
+    name: &'static str,
+    inner: Mutex<T>,
+}
+
+pub struct OrderedGuard<'a, T> {
+    id: usize,
+    guard: MutexGuard<'a, T>,
+}
+
+fn thread_name() -> String {
+    let thread = std::thread::current();
+    match thread.name() {
+        Some(name) => name.to_string(),
+        None => format!("{:?}", thread.id()),
+    }
+}
+
+// the chain of recorded acquisitions leading from `from` to `to`, if there is one
+fn path(
+    order: &BTreeMap<(usize, usize), Acquisition>,
+    from: usize,
+    to: usize,
+) -> Option<Vec<(usize, usize)>> {
+    // for every lock we got to, the edge we got there by
+    let mut reached_by = HashMap::new();
+    let mut todo = vec![from];
+    while let Some(lock) = todo.pop() {
+        if lock == to {
+            let mut path = vec![];
+            let mut at = to;
+            while at != from {
+                let edge: (usize, usize) = reached_by[&at];
+                path.push(edge);
+                at = edge.0;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for &(held, acquired) in order.keys().filter(|(held, _)| *held == lock) {
+            if acquired != from && !reached_by.contains_key(&acquired) {
+                reached_by.insert(acquired, (held, acquired));
+                todo.push(acquired);
+            }
+        }
+    }
+    None
+}
+
+impl<T> OrderedMutex<T> {
+    pub fn new(name: &'static str, value: T) -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            name,
+            inner: Mutex::new(value),
+        }
+    }
+
+    // checking and recording happen under one global lock, so of two threads
+    // racing towards a deadlock the second one always sees the first one's order
+    fn check_order(&self) -> Result<(), DeadlockError> {
+        let held = HELD.with(|held| held.borrow().clone());
+        if held.iter().any(|(id, _)| *id == self.id) {
+            return Err(DeadlockError::Reentrant {
+                thread: thread_name(),
+                lock: self.name,
+            });
+        }
+
+        let mut order = LOCK_ORDER.lock().unwrap_or_else(PoisonError::into_inner);
+        for (held_id, held_name) in &held {
+            if let Some(path) = path(&order, self.id, *held_id) {
+                // the acquisition that started the chain back to what we hold
+                let earlier = order[&path[0]].clone();
+                return Err(DeadlockError::Inversion {
+                    earlier,
+                    now: Acquisition {
+                        thread: thread_name(),
+                        first: held_name,
+                        then: self.name,
+                    },
+                });
+            }
+        }
+        for (held_id, held_name) in held {
+            order
+                .entry((held_id, self.id))
+                .or_insert_with(|| Acquisition {
+                    thread: thread_name(),
+                    first: held_name,
+                    then: self.name,
+                });
+        }
+        Ok(())
+    }
+
+    pub fn lock(&self) -> Result<OrderedGuard<'_, T>, DeadlockError> {
+        if cfg!(debug_assertions) {
+            self.check_order()?;
+        }
+        let guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        HELD.with(|held| held.borrow_mut().push((self.id, self.name)));
+        Ok(OrderedGuard { id: self.id, guard })
+    }
+}
+
+impl<T> Deref for OrderedGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<T> DerefMut for OrderedGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard
+    }
+}
+
+impl<T> Drop for OrderedGuard<'_, T> {
+    fn drop(&mut self) {
+        HELD.with(|held| held.borrow_mut().retain(|(id, _)| *id != self.id));
+    }
+}
+
+// two threads each take one lock, wait for each other and then take the other
+// one: with a plain Mutex this hangs forever, here the second thread gets an error
+pub fn inverted_locking() -> Vec<Result<(), DeadlockError>> {
+    let accounts = Arc::new(OrderedMutex::new("accounts", 100));
+    let audit_log = Arc::new(OrderedMutex::new("audit_log", Vec::<String>::new()));
+    let both_locked = Arc::new(Barrier::new(2));
+
+    let transfer = {
+        let (accounts, audit_log, both_locked) = (
+            Arc::clone(&accounts),
+            Arc::clone(&audit_log),
+            Arc::clone(&both_locked),
+        );
+        std::thread::Builder::new()
+            .name("transfer".to_string())
+            .spawn(move || -> Result<(), DeadlockError> {
+                let mut balance = accounts.lock()?;
+                both_locked.wait();
+                *balance -= 10;
+                audit_log.lock()?.push("transferred 10".to_string());
+                Ok(())
+            })
+            .unwrap()
+    };
+
+    let audit = std::thread::Builder::new()
+        .name("audit".to_string())
+        .spawn(move || -> Result<(), DeadlockError> {
+            let mut log = audit_log.lock()?;
+            both_locked.wait();
+            // give the transfer thread time to start waiting for the audit_log
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            let balance = accounts.lock()?;
+            log.push(format!("balance is {}", *balance));
+            Ok(())
+        })
+        .unwrap();
+
+    vec![transfer.join().unwrap(), audit.join().unwrap()]
+}
+
+pub fn lock_ordering() {
+    if !cfg!(debug_assertions) {
+        println!("lock order tracking only runs in debug builds");
+        return;
+    }
+    for result in inverted_locking() {
+        match result {
+            Ok(()) => println!("thread finished"),
+            Err(error) => println!("{error}"),
+        }
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inversion_is_reported_instead_of_hanging() {
+        let results = inverted_locking();
+        assert_eq!(results[0], Ok(()));
+        match &results[1] {
+            Err(DeadlockError::Inversion { earlier, now }) => {
+                assert_eq!(earlier.thread, "transfer");
+                assert_eq!((earlier.first, earlier.then), ("accounts", "audit_log"));
+                assert_eq!(now.thread, "audit");
+                assert_eq!((now.first, now.then), ("audit_log", "accounts"));
+            }
+            other => panic!("expected an inversion, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn inversion_blames_the_acquisition_on_the_cycle() {
+        let a = Arc::new(OrderedMutex::new("a", ()));
+        let c = Arc::new(OrderedMutex::new("c", ()));
+        let b = Arc::new(OrderedMutex::new("b", ()));
+        let lock_in_order =
+            |name: &str, first: &Arc<OrderedMutex<()>>, then: &Arc<OrderedMutex<()>>| {
+                let (first, then) = (Arc::clone(first), Arc::clone(then));
+                std::thread::Builder::new()
+                    .name(name.to_string())
+                    .spawn(move || {
+                        let _first = first.lock()?;
+                        then.lock().map(|_| ())
+                    })
+                    .unwrap()
+                    .join()
+                    .unwrap()
+            };
+        assert_eq!(lock_in_order("a then c", &a, &c), Ok(()));
+        assert_eq!(lock_in_order("a then b", &a, &b), Ok(()));
+        match lock_in_order("b then a", &b, &a) {
+            Err(DeadlockError::Inversion { earlier, .. }) => {
+                assert_eq!(earlier.thread, "a then b");
+            }
+            other => panic!("expected an inversion, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn consistent_order_and_relocking() {
+        let first = OrderedMutex::new("first", 1);
+        let second = OrderedMutex::new("second", 2);
+        for _ in 0..2 {
+            let a = first.lock().unwrap();
+            let b = second.lock().unwrap();
+            assert_eq!(*a + *b, 3);
+        }
+
+        let _held = first.lock().unwrap();
+        assert!(matches!(
+            first.lock(),
+            Err(DeadlockError::Reentrant { lock: "first", .. })
+        ));
+    }
+}
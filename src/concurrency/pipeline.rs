@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::sync_channel;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::iterators::Measurement;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Aggregate {
+    pub count: usize,
+    pub total: f32,
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Aggregate {
+    fn add(&mut self, value: f32) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        }
+        self.count += 1;
+        self.total += value;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    pub fn average(&self) -> f32 {
+        self.total / self.count as f32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageStats {
+    pub stage: &'static str,
+    pub items: usize,
+    pub elapsed: Duration,
+    // time spent in `send` waiting for the next stage to make room
+    pub blocked: Duration,
+}
+
+impl fmt::Display for StageStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let per_second = self.items as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON);
+        write!(
+            f,
+            "{:<10} {:>6} items in {:>12?} ({:>10.0}/s), blocked on send {:?}",
+            self.stage, self.items, self.elapsed, per_second, self.blocked
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct PipelineReport {
+    pub aggregates: BTreeMap<String, Aggregate>,
+    pub stages: Vec<StageStats>,
+    // the furthest the reader ever got ahead of the parser
+    pub max_ahead: usize,
+}
+
+// reader -> parser -> aggregator, every arrow a sync_channel of `capacity`:
+// when a stage falls behind, `send` in the stage before it blocks
+pub fn run_pipeline(lines: Vec<String>, capacity: usize, parse_delay: Duration) -> PipelineReport {
+    let (line_sender, line_receiver) = sync_channel::<String>(capacity);
+    let (measurement_sender, measurement_receiver) = sync_channel::<Measurement>(capacity);
+    let sent = Arc::new(AtomicUsize::new(0));
+    let received = Arc::new(AtomicUsize::new(0));
+    let max_ahead = Arc::new(AtomicUsize::new(0));
+
+    let reader = {
+        let (sent, received, max_ahead) = (sent.clone(), received.clone(), max_ahead.clone());
+        thread::spawn(move || {
+            let start = Instant::now();
+            let mut blocked = Duration::ZERO;
+            let mut items = 0;
+            for line in lines {
+                let before = Instant::now();
+                line_sender.send(line).unwrap();
+                blocked += before.elapsed();
+                items += 1;
+                let ahead =
+                    sent.fetch_add(1, Ordering::SeqCst) + 1 - received.load(Ordering::SeqCst);
+                max_ahead.fetch_max(ahead, Ordering::SeqCst);
+            }
+            // line_sender is dropped here, which ends the parser's loop
+            StageStats {
+                stage: "reader",
+                items,
+                elapsed: start.elapsed(),
+                blocked,
+            }
+        })
+    };
+
+    let parser = thread::spawn(move || {
+        let start = Instant::now();
+        let mut blocked = Duration::ZERO;
+        let mut items = 0;
+        for line in line_receiver {
+            received.fetch_add(1, Ordering::SeqCst);
+            thread::sleep(parse_delay);
+            if let Some(measurement) = Measurement::new(&line) {
+                let before = Instant::now();
+                measurement_sender.send(measurement).unwrap();
+                blocked += before.elapsed();
+                items += 1;
+            }
+        }
+        StageStats {
+            stage: "parser",
+            items,
+            elapsed: start.elapsed(),
+            blocked,
+        }
+    });
+
+    let aggregator = thread::spawn(move || {
+        let start = Instant::now();
+        let mut aggregates = BTreeMap::<String, Aggregate>::new();
+        let mut items = 0;
+        for measurement in measurement_receiver {
+            aggregates
+                .entry(measurement.measurement)
+                .or_default()
+                .add(measurement.value);
+            items += 1;
+        }
+        let stats = StageStats {
+            stage: "aggregator",
+            items,
+            elapsed: start.elapsed(),
+            blocked: Duration::ZERO,
+        };
+        (aggregates, stats)
+    });
+
+    let reader = reader.join().unwrap();
+    let parser = parser.join().unwrap();
+    let (aggregates, aggregator) = aggregator.join().unwrap();
+    PipelineReport {
+        aggregates,
+        stages: vec![reader, parser, aggregator],
+        max_ahead: max_ahead.load(Ordering::SeqCst),
+    }
+}
+
+pub fn measurement_lines(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|index| match index % 4 {
+            0 => format!("2024{:04} temperature {}.5", index, 15 + index % 10),
+            1 => format!("2024{:04} humidity {}", index, 40 + index % 30),
+            2 => format!("2024{:04} pressure {}", index, 1000 + index % 20),
+            _ => "\n".to_string(),
+        })
+        .collect()
+}
+
+pub fn bounded_pipeline() {
+    const CAPACITY: usize = 8;
+    let report = run_pipeline(measurement_lines(400), CAPACITY, Duration::from_micros(200));
+
+    for (measurement, aggregate) in &report.aggregates {
+        println!(
+            "{measurement}: {} values, average {:.2}, min {}, max {}",
+            aggregate.count,
+            aggregate.average(),
+            aggregate.min,
+            aggregate.max
+        );
+    }
+    for stage in &report.stages {
+        println!("{stage}");
+    }
+    println!(
+        "the reader never got more than {} lines ahead with a capacity of {CAPACITY}",
+        report.max_ahead
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pipeline_aggregates_every_valid_line_and_shuts_down() {
+        let report = run_pipeline(measurement_lines(100), 4, Duration::ZERO);
+        assert_eq!(report.aggregates["temperature"].count, 25);
+        assert_eq!(report.aggregates["humidity"].count, 25);
+        assert_eq!(report.aggregates["pressure"].count, 25);
+        assert_eq!(report.aggregates["temperature"].min, 15.5);
+        assert_eq!(report.aggregates["temperature"].max, 23.5);
+        let items = report
+            .stages
+            .iter()
+            .map(|stage| stage.items)
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec![100, 75, 75]);
+    }
+
+    #[test]
+    fn slow_parser_applies_backpressure_to_the_reader() {
+        let report = run_pipeline(measurement_lines(40), 2, Duration::from_millis(2));
+        // the channel holds 2, the parser holds 1 and the reader may count before the parser does
+        assert!(
+            report.max_ahead <= 4,
+            "reader got {} ahead",
+            report.max_ahead
+        );
+        assert!(report.stages[0].blocked >= Duration::from_millis(20));
+    }
+}
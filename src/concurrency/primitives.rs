@@ -0,0 +1,245 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier, Condvar, Mutex, RwLock};
+use std::thread;
+
+// many readers at once, a writer waits until they are all gone
+#[derive(Debug, Default)]
+pub struct ReadCache {
+    values: RwLock<HashMap<String, f32>>,
+    misses: AtomicUsize,
+}
+
+impl ReadCache {
+    pub fn get(&self, key: &str) -> Option<f32> {
+        let value = self.values.read().unwrap().get(key).copied();
+        if value.is_none() {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+        value
+    }
+
+    // only takes the write lock when the value is missing
+    pub fn get_or_insert_with(&self, key: &str, compute: impl FnOnce() -> f32) -> f32 {
+        if let Some(value) = self.get(key) {
+            return value;
+        }
+        *self
+            .values
+            .write()
+            .unwrap()
+            .entry(key.to_string())
+            .or_insert_with(compute)
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+// a queue where consumers sleep on a Condvar until there is work or it is closed
+#[derive(Debug, Default)]
+pub struct WorkQueue<T> {
+    state: Mutex<(VecDeque<T>, bool)>,
+    available: Condvar,
+}
+
+impl<T> WorkQueue<T> {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new((VecDeque::new(), false)),
+            available: Condvar::new(),
+        }
+    }
+
+    pub fn push(&self, item: T) {
+        self.state.lock().unwrap().0.push_back(item);
+        self.available.notify_one();
+    }
+
+    pub fn close(&self) {
+        self.state.lock().unwrap().1 = true;
+        self.available.notify_all();
+    }
+
+    // None once the queue is closed and drained
+    pub fn pop(&self) -> Option<T> {
+        let mut state = self
+            .available
+            .wait_while(self.state.lock().unwrap(), |(items, closed)| {
+                items.is_empty() && !*closed
+            })
+            .unwrap();
+        state.0.pop_front()
+    }
+}
+
+pub fn rwlock_cache() {
+    let cache = Arc::new(ReadCache::default());
+    let handles = (0..4)
+        .map(|reader| {
+            let cache = Arc::clone(&cache);
+            thread::spawn(move || cache.get_or_insert_with("temperature", || 19.0 + reader as f32))
+        })
+        .collect::<Vec<_>>();
+    let values = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<f32>>();
+    println!("every reader sees the first value written: {:?}", values);
+    println!("cache misses: {}", cache.misses());
+}
+
+// squares every number with `consumers` threads and returns the sorted results
+pub fn produce_and_consume(numbers: Vec<u64>, consumers: usize) -> Vec<u64> {
+    let queue = Arc::new(WorkQueue::new());
+    let handles = (0..consumers)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                let mut squared = vec![];
+                while let Some(number) = queue.pop() {
+                    squared.push(number * number);
+                }
+                squared
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for number in numbers {
+        queue.push(number);
+    }
+    queue.close();
+
+    let mut results = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect::<Vec<u64>>();
+    results.sort();
+    results
+}
+
+pub fn condvar_queue() {
+    println!(
+        "squared by 3 consumers: {:?}",
+        produce_and_consume((1..=10).collect(), 3)
+    );
+}
+
+// phase 1 doubles every slot, phase 2 adds the right neighbour's phase 1 result;
+// without the barrier a thread could read a neighbour that is not doubled yet
+pub fn phased(values: Vec<i64>) -> Vec<i64> {
+    let threads = values.len();
+    let doubled = Arc::new((0..threads).map(|_| Mutex::new(0)).collect::<Vec<_>>());
+    let barrier = Arc::new(Barrier::new(threads));
+
+    let handles = values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let doubled = Arc::clone(&doubled);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                *doubled[index].lock().unwrap() = value * 2;
+                barrier.wait();
+                let neighbour = *doubled[(index + 1) % threads].lock().unwrap();
+                value * 2 + neighbour
+            })
+        })
+        .collect::<Vec<_>>();
+    handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect()
+}
+
+pub fn barrier_phases() {
+    println!("phased result: {:?}", phased(vec![1, 2, 3, 4]));
+}
+
+// Relaxed is enough for a counter, nobody reads other memory based on it
+pub fn relaxed_count(threads: usize, increments: usize) -> usize {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let handles = (0..threads)
+        .map(|_| {
+            let counter = Arc::clone(&counter);
+            thread::spawn(move || {
+                for _ in 0..increments {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    counter.load(Ordering::Relaxed)
+}
+
+// the Release store on `ready` makes the earlier write to `data` visible to
+// whoever sees `ready` with an Acquire load
+pub fn publish_with_release_acquire(value: usize) -> usize {
+    let data = Arc::new(AtomicUsize::new(0));
+    let ready = Arc::new(AtomicBool::new(false));
+
+    let reader = {
+        let (data, ready) = (Arc::clone(&data), Arc::clone(&ready));
+        thread::spawn(move || {
+            while !ready.load(Ordering::Acquire) {
+                std::hint::spin_loop();
+            }
+            data.load(Ordering::Relaxed)
+        })
+    };
+
+    data.store(value, Ordering::Relaxed);
+    ready.store(true, Ordering::Release);
+    reader.join().unwrap()
+}
+
+pub fn atomic_counters() {
+    println!("relaxed counter: {}", relaxed_count(4, 10_000));
+    println!(
+        "published with release/acquire: {}",
+        publish_with_release_acquire(42)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_computes_a_missing_value_once() {
+        let cache = ReadCache::default();
+        assert_eq!(cache.get("temperature"), None);
+        assert_eq!(cache.get_or_insert_with("temperature", || 19.0), 19.0);
+        assert_eq!(cache.get_or_insert_with("temperature", || 21.0), 19.0);
+        assert_eq!(cache.misses(), 2);
+    }
+
+    #[test]
+    fn queue_delivers_every_item_once() {
+        let numbers = (1..=100).collect::<Vec<u64>>();
+        let expected = numbers.iter().map(|n| n * n).collect::<Vec<u64>>();
+        assert_eq!(produce_and_consume(numbers, 4), expected);
+    }
+
+    #[test]
+    fn closed_empty_queue_does_not_block() {
+        let queue = WorkQueue::<u8>::new();
+        queue.close();
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn phases_see_each_others_results() {
+        assert_eq!(phased(vec![1, 2, 3, 4]), vec![6, 10, 14, 10]);
+    }
+
+    #[test]
+    fn atomics_count_and_publish() {
+        assert_eq!(relaxed_count(4, 1_000), 4_000);
+        assert_eq!(publish_with_release_acquire(42), 42);
+    }
+}
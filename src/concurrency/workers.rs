@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Job {
+    // sum of 1..=n
+    Compute(u64),
+    Sleep(Duration),
+    Fail(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobError {
+    Failed(String),
+    // the pool was cancelled before or while the job ran
+    Cancelled,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reply {
+    pub id: usize,
+    pub worker: usize,
+    pub result: Result<u64, JobError>,
+}
+
+#[derive(Debug)]
+pub struct Collected {
+    pub replies: BTreeMap<usize, Reply>,
+    pub timed_out: bool,
+}
+
+impl fmt::Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.result {
+            Ok(value) => write!(f, "job {} on worker {}: {value}", self.id, self.worker),
+            Err(JobError::Failed(reason)) => {
+                write!(
+                    f,
+                    "job {} on worker {} failed: {reason}",
+                    self.id, self.worker
+                )
+            }
+            Err(JobError::Cancelled) => {
+                write!(f, "job {} on worker {} was cancelled", self.id, self.worker)
+            }
+        }
+    }
+}
+
+fn perform(job: Job, cancelled: &AtomicBool) -> Result<u64, JobError> {
+    match job {
+        Job::Compute(n) => u64::try_from(n as u128 * (n as u128 + 1) / 2)
+            .map_err(|_| JobError::Failed(format!("sum of 1..={n} overflows a u64"))),
+        // sleeps in small steps so a cancel does not have to wait for the whole duration
+        Job::Sleep(duration) => {
+            let until = Instant::now() + duration;
+            while Instant::now() < until {
+                if cancelled.load(Ordering::Relaxed) {
+                    return Err(JobError::Cancelled);
+                }
+                thread::sleep(Duration::from_millis(5).min(until - Instant::now()));
+            }
+            Ok(duration.as_millis() as u64)
+        }
+        Job::Fail(reason) => Err(JobError::Failed(reason)),
+    }
+}
+
+// the dispatcher fans jobs out over one shared receiver, replies fan back in
+// over a single channel tagged with the job id
+pub struct WorkerPool {
+    jobs: Sender<(usize, Job)>,
+    replies: Receiver<Reply>,
+    cancelled: Arc<AtomicBool>,
+    handles: Vec<JoinHandle<()>>,
+    submitted: usize,
+}
+
+impl WorkerPool {
+    pub fn new(workers: usize) -> Self {
+        let (jobs, job_receiver) = channel::<(usize, Job)>();
+        let (reply_sender, replies) = channel();
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+        let cancelled = Arc::new(AtomicBool::new(false));
+
+        let handles = (0..workers)
+            .map(|worker| {
+                let job_receiver = Arc::clone(&job_receiver);
+                let reply_sender = reply_sender.clone();
+                let cancelled = Arc::clone(&cancelled);
+                thread::spawn(move || loop {
+                    // the lock is released as soon as a job is taken off the channel
+                    let next = job_receiver.lock().unwrap().recv();
+                    let Ok((id, job)) = next else {
+                        break;
+                    };
+                    let result = if cancelled.load(Ordering::Relaxed) {
+                        Err(JobError::Cancelled)
+                    } else {
+                        perform(job, &cancelled)
+                    };
+                    // the collector may already be gone after a timeout
+                    let _ = reply_sender.send(Reply { id, worker, result });
+                })
+            })
+            .collect();
+
+        Self {
+            jobs,
+            replies,
+            cancelled,
+            handles,
+            submitted: 0,
+        }
+    }
+
+    pub fn submit(&mut self, job: Job) -> usize {
+        let id = self.submitted;
+        self.jobs.send((id, job)).unwrap();
+        self.submitted += 1;
+        id
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    // waits at most `timeout` for every reply, then cancels whatever is left
+    pub fn collect(self, timeout: Duration) -> Collected {
+        let WorkerPool {
+            jobs,
+            replies,
+            cancelled,
+            handles,
+            submitted,
+        } = self;
+        drop(jobs);
+
+        let deadline = Instant::now() + timeout;
+        let mut collected = Collected {
+            replies: BTreeMap::new(),
+            timed_out: false,
+        };
+        while collected.replies.len() < submitted {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match replies.recv_timeout(remaining) {
+                Ok(reply) => {
+                    collected.replies.insert(reply.id, reply);
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    collected.timed_out = true;
+                    cancelled.store(true, Ordering::Relaxed);
+                    // the workers answer the remaining jobs with Cancelled
+                    for reply in replies.iter() {
+                        collected.replies.insert(reply.id, reply);
+                    }
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        collected
+    }
+}
+
+pub fn worker_pool() {
+    let mut pool = WorkerPool::new(3);
+    pool.submit(Job::Compute(1_000));
+    pool.submit(Job::Sleep(Duration::from_millis(20)));
+    pool.submit(Job::Fail("sensor offline".to_string()));
+    pool.submit(Job::Compute(u64::MAX));
+    pool.submit(Job::Sleep(Duration::from_secs(5)));
+    pool.submit(Job::Compute(10));
+
+    let collected = pool.collect(Duration::from_millis(200));
+    for reply in collected.replies.values() {
+        println!("{reply}");
+    }
+    println!("timed out: {}", collected.timed_out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_job_gets_a_reply_with_its_id() {
+        let mut pool = WorkerPool::new(4);
+        let ids = (1..=20)
+            .map(|n| pool.submit(Job::Compute(n)))
+            .collect::<Vec<_>>();
+        let failing = pool.submit(Job::Fail("nope".to_string()));
+
+        let collected = pool.collect(Duration::from_secs(5));
+        assert!(!collected.timed_out);
+        for (n, id) in (1..=20u64).zip(ids) {
+            assert_eq!(collected.replies[&id].result, Ok(n * (n + 1) / 2));
+        }
+        assert_eq!(
+            collected.replies[&failing].result,
+            Err(JobError::Failed("nope".to_string()))
+        );
+    }
+
+    #[test]
+    fn timeout_cancels_long_running_jobs() {
+        let mut pool = WorkerPool::new(1);
+        let quick = pool.submit(Job::Compute(3));
+        let slow = pool.submit(Job::Sleep(Duration::from_secs(10)));
+        let queued = pool.submit(Job::Compute(4));
+
+        let started = Instant::now();
+        let collected = pool.collect(Duration::from_millis(50));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(collected.timed_out);
+        assert_eq!(collected.replies[&quick].result, Ok(6));
+        assert_eq!(collected.replies[&slow].result, Err(JobError::Cancelled));
+        assert_eq!(collected.replies[&queued].result, Err(JobError::Cancelled));
+    }
+
+    #[test]
+    fn cancel_before_collecting_skips_queued_jobs() {
+        let mut pool = WorkerPool::new(2);
+        pool.cancel();
+        let id = pool.submit(Job::Compute(10));
+        let collected = pool.collect(Duration::from_secs(5));
+        assert_eq!(collected.replies[&id].result, Err(JobError::Cancelled));
+    }
+}
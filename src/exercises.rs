@@ -0,0 +1,212 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::panic;
+use std::sync::{Mutex, PoisonError};
+
+pub mod solutions;
+pub mod stubs;
+
+#[derive(Debug)]
+pub struct Exercise {
+    pub name: &'static str,
+    pub topic: &'static str,
+    pub hint: &'static str,
+    // runs the hidden checks against the learner's stub
+    pub check: fn() -> Result<(), String>,
+    // runs the same checks against the reference solution
+    pub solution: fn() -> Result<(), String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Passed,
+    NotImplemented,
+    Failed(String),
+}
+
+impl Exercise {
+    pub fn find(name: &str) -> Option<&'static Exercise> {
+        EXERCISES.iter().find(|exercise| exercise.name == name)
+    }
+
+    pub fn run(&self) -> Outcome {
+        outcome(self.check)
+    }
+}
+
+// the panic hook is global, two checks swapping it at once (tests run in parallel) could
+// leave the silent one installed for good
+static HOOK_SWAP: Mutex<()> = Mutex::new(());
+
+// runs a check, turning panics (like the `todo!()` in a stub) into an outcome
+pub fn outcome(check: fn() -> Result<(), String>) -> Outcome {
+    let _swapping = HOOK_SWAP.lock().unwrap_or_else(PoisonError::into_inner);
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(check);
+    panic::set_hook(previous_hook);
+
+    match result {
+        Ok(Ok(())) => Outcome::Passed,
+        Ok(Err(message)) => Outcome::Failed(message),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            if message.starts_with("not yet implemented") {
+                Outcome::NotImplemented
+            } else {
+                Outcome::Failed(format!("panicked: {message}"))
+            }
+        }
+    }
+}
+
+fn expect_eq<T: Debug + PartialEq>(call: &str, actual: T, expected: T) -> Result<(), String> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!("{call} returned {actual:?}, expected {expected:?}"))
+    }
+}
+
+fn check_take_fifth(take_fifth: fn(&[i32]) -> Option<i32>) -> Result<(), String> {
+    expect_eq(
+        "take_fifth(&[1, 2, 3, 4, 5])",
+        take_fifth(&[1, 2, 3, 4, 5]),
+        Some(5),
+    )?;
+    expect_eq("take_fifth(&[1, 2, 3])", take_fifth(&[1, 2, 3]), None)?;
+    expect_eq("take_fifth(&[])", take_fifth(&[]), None)
+}
+
+fn check_divide(divide: fn(f64, f64) -> Result<f64, String>) -> Result<(), String> {
+    expect_eq("divide(4.0, 2.0)", divide(4.0, 2.0), Ok(2.0))?;
+    if divide(4.0, 0.0).is_ok() {
+        return Err("divide(4.0, 0.0) returned Ok, expected an Err".to_string());
+    }
+    Ok(())
+}
+
+fn check_shout(
+    shout_str: fn(&'static str) -> String,
+    shout_string: fn(String) -> String,
+) -> Result<(), String> {
+    expect_eq("shout(\"hello\")", shout_str("hello"), "HELLO!".to_string())?;
+    expect_eq(
+        "shout(\"hapax\".to_string())",
+        shout_string("hapax".to_string()),
+        "HAPAX!".to_string(),
+    )
+}
+
+fn check_longest(longest: for<'a> fn(&'a str, &'a str) -> &'a str) -> Result<(), String> {
+    let purmerend = "Purmerend".to_string();
+    let amsterdam = "Amsterdam".to_string();
+    expect_eq(
+        "longest(\"Edam\", \"Purmerend\")",
+        longest("Edam", &purmerend),
+        "Purmerend",
+    )?;
+    expect_eq(
+        "longest(\"Purmerend\", \"Amsterdam\")",
+        longest(&purmerend, &amsterdam),
+        "Purmerend",
+    )
+}
+
+fn check_rotate(rotate: fn(VecDeque<i32>) -> VecDeque<i32>) -> Result<(), String> {
+    expect_eq(
+        "rotate([0, 1, 2])",
+        rotate(VecDeque::from(vec![0, 1, 2])),
+        VecDeque::from(vec![1, 2, 0]),
+    )?;
+    expect_eq("rotate([])", rotate(VecDeque::new()), VecDeque::new())
+}
+
+fn check_evens_squared(evens_squared: fn(&[i32]) -> Vec<i32>) -> Result<(), String> {
+    expect_eq(
+        "evens_squared(&[1, 2, 3, 4])",
+        evens_squared(&[1, 2, 3, 4]),
+        vec![4, 16],
+    )?;
+    expect_eq("evens_squared(&[1, 3])", evens_squared(&[1, 3]), vec![])
+}
+
+pub const EXERCISES: &[Exercise] = &[
+    Exercise {
+        name: "take_fifth",
+        topic: "basics",
+        hint: "see option_and_handling, `Vec::get` already returns an Option",
+        check: || check_take_fifth(stubs::take_fifth),
+        solution: || check_take_fifth(solutions::take_fifth),
+    },
+    Exercise {
+        name: "divide",
+        topic: "basics",
+        hint: "see result_and_handling, compare the denominator with 0.0 first",
+        check: || check_divide(stubs::divide),
+        solution: || check_divide(solutions::divide),
+    },
+    Exercise {
+        name: "shout",
+        topic: "basics",
+        hint: "see traitbounds, `input.as_ref()` gives you a &str",
+        check: || check_shout(stubs::shout, stubs::shout),
+        solution: || check_shout(solutions::shout, solutions::shout),
+    },
+    Exercise {
+        name: "longest",
+        topic: "ownership",
+        hint: "see lifetimes, both inputs and the output share the lifetime 'a",
+        check: || check_longest(stubs::longest),
+        solution: || check_longest(solutions::longest),
+    },
+    Exercise {
+        name: "rotate",
+        topic: "collections",
+        hint: "see vecdeq, `pop_front` and `push_back` need a mutable queue",
+        check: || check_rotate(stubs::rotate),
+        solution: || check_rotate(solutions::rotate),
+    },
+    Exercise {
+        name: "evens_squared",
+        topic: "iterators",
+        hint: "see iterators and closures, chain `filter` before `map`",
+        check: || check_evens_squared(stubs::evens_squared),
+        solution: || check_evens_squared(solutions::evens_squared),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solutions_pass_their_checks() {
+        for exercise in EXERCISES {
+            assert_eq!(
+                outcome(exercise.solution),
+                Outcome::Passed,
+                "{}",
+                exercise.name
+            );
+        }
+    }
+
+    #[test]
+    fn wrong_answers_and_todos_are_reported() {
+        assert_eq!(
+            outcome(|| check_take_fifth(|vector| vector.first().copied())),
+            Outcome::Failed(
+                "take_fifth(&[1, 2, 3, 4, 5]) returned Some(1), expected Some(5)".to_string()
+            )
+        );
+        assert_eq!(
+            outcome(|| check_evens_squared(|_| todo!())),
+            Outcome::NotImplemented
+        );
+    }
+}
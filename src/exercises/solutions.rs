@@ -0,0 +1,40 @@
+use std::collections::VecDeque;
+
+pub fn take_fifth(vector: &[i32]) -> Option<i32> {
+    vector.get(4).copied()
+}
+
+pub fn divide(numerator: f64, denominator: f64) -> Result<f64, String> {
+    if denominator == 0.0 {
+        Err("cannot divide by zero".to_string())
+    } else {
+        Ok(numerator / denominator)
+    }
+}
+
+pub fn shout<T: AsRef<str>>(input: T) -> String {
+    format!("{}!", input.as_ref().to_uppercase())
+}
+
+pub fn longest<'a>(first: &'a str, second: &'a str) -> &'a str {
+    if second.len() > first.len() {
+        second
+    } else {
+        first
+    }
+}
+
+pub fn rotate(mut queue: VecDeque<i32>) -> VecDeque<i32> {
+    if let Some(item) = queue.pop_front() {
+        queue.push_back(item);
+    }
+    queue
+}
+
+pub fn evens_squared(numbers: &[i32]) -> Vec<i32> {
+    numbers
+        .iter()
+        .filter(|number| *number % 2 == 0)
+        .map(|number| number * number)
+        .collect()
+}
@@ -0,0 +1,34 @@
+// fill in these functions, then check them with `cargo run -- exercises`
+#![allow(unused_variables)]
+
+use std::collections::VecDeque;
+
+// basics: return the fifth element, or None when the vector is too short
+pub fn take_fifth(vector: &[i32]) -> Option<i32> {
+    todo!()
+}
+
+// basics: divide, but return an Err instead of dividing by zero
+pub fn divide(numerator: f64, denominator: f64) -> Result<f64, String> {
+    todo!()
+}
+
+// basics: accept anything stringlike and return it uppercased with a "!"
+pub fn shout<T: AsRef<str>>(input: T) -> String {
+    todo!()
+}
+
+// ownership: return whichever string slice is longest, the first one on a tie
+pub fn longest<'a>(first: &'a str, second: &'a str) -> &'a str {
+    todo!()
+}
+
+// collections: move the first item to the back, like a rotating playlist
+pub fn rotate(queue: VecDeque<i32>) -> VecDeque<i32> {
+    todo!()
+}
+
+// iterators: square only the even numbers, keeping their order
+pub fn evens_squared(numbers: &[i32]) -> Vec<i32> {
+    todo!()
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+pub fn chaining() {
+    let range = 1..=10;
+    let vec = range.collect::<Vec<u8>>();
+    println!("numbers 1 to 10: {:?}", vec);
+
+    let subvec = vec.into_iter().skip(2).take(3).collect::<Vec<u8>>();
+    println!("{:?}", subvec);
+}
+
+pub fn plus_two(vector: &[i32]) -> Vec<i32> {
+    vector.iter().map(|x| x + 2).collect::<Vec<i32>>()
+}
+
+pub fn squared(vector: &[i32]) -> Vec<i32> {
+    vector.iter().map(|x| x * x).collect::<Vec<i32>>()
+}
+
+pub fn plus_hundred_in_place(vector: &mut [i32]) {
+    vector.iter_mut().for_each(|x| *x += 100);
+}
+
+pub fn iterators() {
+    let vector = (0..=10).collect::<Vec<i32>>();
+    let vector_a = plus_two(&vector);
+    let vector_b = squared(&vector);
+
+    let mut vector_mut = vector.clone();
+    plus_hundred_in_place(&mut vector_mut);
+    println!("{:?}", vector_a);
+    println!("{:?}", vector_b);
+    println!("{:?}", vector);
+}
+
+#[allow(clippy::useless_vec)]
+pub fn closures() {
+    let outside = 4;
+    let closure = |inside: i32| outside + inside;
+    println!("calling closure {:?}", closure(5));
+
+    let num_vec = vec![2, 4, 6];
+    let double_vec = &num_vec.iter().map(|x| x * 2).collect::<Vec<i32>>();
+
+    println!("mapped vector{:?}", double_vec);
+
+    num_vec.iter().enumerate().for_each(|(index, value)| {
+        println!("{}:{}", index, value);
+    });
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub date: String,
+    pub measurement: String,
+    pub value: f32,
+}
+
+impl Measurement {
+    pub fn new(line: &str) -> Option<Measurement> {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        if parts.len() != 3 {
+            return None;
+        }
+        let value = parts[2].parse::<f32>();
+        if value.is_err() {
+            return None;
+        }
+        Some(Self {
+            measurement: parts[1].to_string(),
+            date: parts[0].to_string(),
+            value: value.unwrap(),
+        })
+    }
+    pub fn print(&self) {
+        println!("{}, {}, {}", self.date, self.measurement, self.value,);
+    }
+}
+
+pub const MEASUREMENT_LOGS: [&str; 7] = [
+    "20240917 temperature 19.0",
+    "20240916 temperature 18.0",
+    "\n",
+    "20240915 temperature ",
+    "20240914 temperature 0",
+    "\n",
+    "20240912 temperature 21.0",
+];
+
+pub fn functional() {
+    let measurement_logs = MEASUREMENT_LOGS;
+
+    let measurements = measurement_logs
+        .iter()
+        .filter_map(|line| Measurement::new(line))
+        .collect::<Vec<Measurement>>();
+    println!("{:?}", measurements);
+    measurements[0].print();
+}
+
+// vec! where an array would do, and find with an explicit return
+#[allow(clippy::useless_vec, clippy::needless_return)]
+pub fn some_and_find() {
+    let some_are_none = vec![Some("yes"), Some("yes"), None];
+    let result1 = some_are_none.iter().all(|x| x.is_some());
+
+    let result2 = some_are_none.iter().any(|x| x.is_some());
+
+    println!("all are some: {}", result1);
+    println!("some are some: {}", result2);
+
+    let some_are_none = vec![Some(1), Some(1), None];
+    let folded_total = some_are_none
+        .iter()
+        .fold(0, |total_so_far, next| total_so_far + next.unwrap_or(0));
+    println!("folded total: {}", folded_total);
+
+    let found_item = some_are_none
+        .iter()
+        .rev() //start at the end
+        .find(|item| {
+            return item.unwrap_or(0) == 1;
+        });
+    println!("found item: {}", found_item.unwrap().unwrap());
+}
+
+pub fn cycle() {
+    let even_odd = vec!["even", "odd"];
+
+    let even_odd_vec = (0..6)
+        .zip(even_odd.into_iter().cycle())
+        .collect::<Vec<(i32, &str)>>();
+    println!("{:?}", even_odd_vec);
+}
@@ -0,0 +1,455 @@
+pub mod allocations;
+pub mod asynchronous;
+pub mod basics;
+pub mod collections;
+pub mod concurrency;
+pub mod exercises;
+pub mod iterators;
+pub mod listing;
+pub mod ownership;
+pub mod print_things;
+pub mod progress;
+pub mod serialization;
+pub mod smart_pointers;
+
+#[derive(Debug)]
+pub struct Lesson {
+    pub name: &'static str,
+    // path of the module defining the lesson, e.g. "concurrency::contention"
+    pub module: &'static str,
+    pub run: fn(),
+}
+
+impl Lesson {
+    pub fn find(name: &str) -> Option<&'static Lesson> {
+        LESSONS.iter().find(|lesson| lesson.name == name)
+    }
+
+    // the top level module is the topic
+    pub fn topic(&self) -> &'static str {
+        self.module.split("::").next().unwrap_or(self.module)
+    }
+
+    pub fn listing(&self) -> Option<listing::Listing> {
+        listing::extract(listing::source(self.module)?, self.name)
+    }
+}
+
+// every lesson in the order the runner plays them
+pub const LESSONS: &[Lesson] = &[
+    Lesson {
+        name: "mutability",
+        module: "basics",
+        run: || {
+            basics::mutability();
+        },
+    },
+    Lesson {
+        name: "types",
+        module: "basics",
+        run: || {
+            basics::types();
+        },
+    },
+    Lesson {
+        name: "byte_manipulation",
+        module: "basics",
+        run: || {
+            basics::byte_manipulation();
+        },
+    },
+    Lesson {
+        name: "hexdump",
+        module: "basics::hexdump",
+        run: || {
+            basics::hexdump::hexdump();
+        },
+    },
+    Lesson {
+        name: "references",
+        module: "ownership",
+        run: || {
+            ownership::references();
+        },
+    },
+    Lesson {
+        name: "moving_references",
+        module: "ownership",
+        run: || {
+            ownership::moving_references();
+        },
+    },
+    Lesson {
+        name: "unicode",
+        module: "basics",
+        run: || {
+            basics::unicode();
+        },
+    },
+    Lesson {
+        name: "text_statistics",
+        module: "basics::text",
+        run: || {
+            basics::text::text_statistics();
+        },
+    },
+    Lesson {
+        name: "escapes",
+        module: "basics::escape",
+        run: || {
+            basics::escape::escapes();
+        },
+    },
+    Lesson {
+        name: "string_literals",
+        module: "basics::literals",
+        run: || {
+            basics::literals::string_literals();
+        },
+    },
+    Lesson {
+        name: "arrays",
+        module: "basics",
+        run: || {
+            basics::arrays();
+        },
+    },
+    Lesson {
+        name: "vectors_and_tuples",
+        module: "collections",
+        run: || {
+            collections::vectors_and_tuples();
+        },
+    },
+    Lesson {
+        name: "vec_growth",
+        module: "collections::growth",
+        run: || {
+            collections::growth::vec_growth();
+        },
+    },
+    Lesson {
+        name: "looping_and_matching",
+        module: "basics",
+        run: || {
+            basics::looping_and_matching();
+        },
+    },
+    Lesson {
+        name: "structs_enums_and_impl",
+        module: "basics",
+        run: || {
+            basics::structs_enums_and_impl();
+        },
+    },
+    Lesson {
+        name: "option_and_handling",
+        module: "basics",
+        run: || {
+            basics::option_and_handling();
+        },
+    },
+    Lesson {
+        name: "result_and_handling",
+        module: "basics",
+        run: || {
+            basics::result_and_handling();
+        },
+    },
+    Lesson {
+        name: "vecdeq",
+        module: "collections",
+        run: || {
+            collections::vecdeq();
+        },
+    },
+    Lesson {
+        name: "ring_buffer",
+        module: "collections::ring",
+        run: || {
+            collections::ring::ring_buffer();
+        },
+    },
+    Lesson {
+        name: "traitbounds",
+        module: "basics",
+        run: || {
+            basics::traitbounds();
+        },
+    },
+    Lesson {
+        name: "chaining",
+        module: "iterators",
+        run: || {
+            iterators::chaining();
+        },
+    },
+    Lesson {
+        name: "iterators",
+        module: "iterators",
+        run: || {
+            iterators::iterators();
+        },
+    },
+    Lesson {
+        name: "closures",
+        module: "iterators",
+        run: || {
+            iterators::closures();
+        },
+    },
+    Lesson {
+        name: "functional",
+        module: "iterators",
+        run: || {
+            iterators::functional();
+        },
+    },
+    Lesson {
+        name: "some_and_find",
+        module: "iterators",
+        run: || {
+            iterators::some_and_find();
+        },
+    },
+    Lesson {
+        name: "cycle",
+        module: "iterators",
+        run: || {
+            iterators::cycle();
+        },
+    },
+    Lesson {
+        name: "debug_logging",
+        module: "basics",
+        run: || {
+            basics::debug_logging();
+        },
+    },
+    Lesson {
+        name: "lifetimes",
+        module: "ownership",
+        run: || {
+            ownership::lifetimes();
+        },
+    },
+    Lesson {
+        name: "cell_refcel",
+        module: "smart_pointers",
+        run: || {
+            smart_pointers::cell_refcel();
+        },
+    },
+    Lesson {
+        name: "mutex",
+        module: "concurrency",
+        run: || {
+            concurrency::mutex();
+        },
+    },
+    Lesson {
+        name: "multithreading_naive",
+        module: "concurrency",
+        run: || {
+            concurrency::multithreading_naive();
+        },
+    },
+    Lesson {
+        name: "multithreading",
+        module: "concurrency",
+        run: || {
+            concurrency::multithreading();
+        },
+    },
+    Lesson {
+        name: "lock_contention",
+        module: "concurrency::contention",
+        run: || {
+            concurrency::contention::lock_contention();
+        },
+    },
+    Lesson {
+        name: "lock_ordering",
+        module: "concurrency::deadlock",
+        run: || {
+            concurrency::deadlock::lock_ordering();
+        },
+    },
+    Lesson {
+        name: "rwlock_cache",
+        module: "concurrency::primitives",
+        run: || {
+            concurrency::primitives::rwlock_cache();
+        },
+    },
+    Lesson {
+        name: "condvar_queue",
+        module: "concurrency::primitives",
+        run: || {
+            concurrency::primitives::condvar_queue();
+        },
+    },
+    Lesson {
+        name: "barrier_phases",
+        module: "concurrency::primitives",
+        run: || {
+            concurrency::primitives::barrier_phases();
+        },
+    },
+    Lesson {
+        name: "atomic_counters",
+        module: "concurrency::primitives",
+        run: || {
+            concurrency::primitives::atomic_counters();
+        },
+    },
+    Lesson {
+        name: "channels",
+        module: "concurrency",
+        run: || {
+            concurrency::channels();
+        },
+    },
+    Lesson {
+        name: "bounded_pipeline",
+        module: "concurrency::pipeline",
+        run: || {
+            concurrency::pipeline::bounded_pipeline();
+        },
+    },
+    Lesson {
+        name: "worker_pool",
+        module: "concurrency::workers",
+        run: || {
+            concurrency::workers::worker_pool();
+        },
+    },
+    Lesson {
+        name: "big_multithreading",
+        module: "concurrency",
+        run: || {
+            concurrency::big_multithreading();
+        },
+    },
+    Lesson {
+        name: "box_heap",
+        module: "smart_pointers",
+        run: || {
+            smart_pointers::box_heap();
+        },
+    },
+    Lesson {
+        name: "memory_layout",
+        module: "smart_pointers::layout",
+        run: || {
+            smart_pointers::layout::memory_layout();
+        },
+    },
+    Lesson {
+        name: "dyn_trait",
+        module: "smart_pointers",
+        run: || {
+            smart_pointers::dyn_trait();
+        },
+    },
+    Lesson {
+        name: "dereferencing",
+        module: "smart_pointers",
+        run: || {
+            smart_pointers::dereferencing();
+        },
+    },
+    Lesson {
+        name: "mods",
+        module: "basics",
+        run: || {
+            basics::mods();
+        },
+    },
+    Lesson {
+        name: "rayon",
+        module: "concurrency",
+        run: || {
+            concurrency::rayon();
+        },
+    },
+    Lesson {
+        name: "serde",
+        module: "serialization",
+        run: || {
+            serialization::serde();
+        },
+    },
+    Lesson {
+        name: "futures",
+        module: "asynchronous",
+        run: || {
+            asynchronous::futures();
+        },
+    },
+    Lesson {
+        name: "executor",
+        module: "asynchronous::executor",
+        run: || {
+            asynchronous::executor::executor();
+        },
+    },
+    Lesson {
+        name: "streams",
+        module: "asynchronous::streams",
+        run: || {
+            asynchronous::streams::streams();
+        },
+    },
+    Lesson {
+        name: "tokio",
+        module: "asynchronous",
+        run: || {
+            asynchronous::tokio();
+        },
+    },
+    Lesson {
+        name: "async_channels",
+        module: "asynchronous::tasks",
+        run: || {
+            asynchronous::tasks::async_channels();
+        },
+    },
+    Lesson {
+        name: "async_multithreading",
+        module: "asynchronous::tasks",
+        run: || {
+            asynchronous::tasks::async_multithreading();
+        },
+    },
+    Lesson {
+        name: "async_mutexes",
+        module: "asynchronous::tasks",
+        run: || {
+            asynchronous::tasks::async_mutexes();
+        },
+    },
+    Lesson {
+        name: "cancellation",
+        module: "asynchronous::cancellation",
+        run: || {
+            asynchronous::cancellation::cancellation();
+        },
+    },
+    Lesson {
+        name: "chat_server",
+        module: "asynchronous::chat",
+        run: || {
+            #[cfg(not(loom))]
+            asynchronous::chat::chat_server();
+        },
+    },
+    Lesson {
+        name: "measurement_service",
+        module: "asynchronous::http",
+        run: || {
+            #[cfg(not(loom))]
+            asynchronous::http::measurement_service();
+        },
+    },
+];
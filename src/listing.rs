@@ -0,0 +1,110 @@
+use std::fmt;
+
+// the lessons embed their own source so a lesson can be printed next to its output
+pub fn source(module: &str) -> Option<&'static str> {
+    match module {
+        "asynchronous" => Some(include_str!("asynchronous.rs")),
+        "asynchronous::cancellation" => Some(include_str!("asynchronous/cancellation.rs")),
+        "asynchronous::chat" => Some(include_str!("asynchronous/chat.rs")),
+        "asynchronous::executor" => Some(include_str!("asynchronous/executor.rs")),
+        "asynchronous::http" => Some(include_str!("asynchronous/http.rs")),
+        "asynchronous::streams" => Some(include_str!("asynchronous/streams.rs")),
+        "asynchronous::tasks" => Some(include_str!("asynchronous/tasks.rs")),
+        "basics" => Some(include_str!("basics.rs")),
+        "basics::escape" => Some(include_str!("basics/escape.rs")),
+        "basics::hexdump" => Some(include_str!("basics/hexdump.rs")),
+        "basics::literals" => Some(include_str!("basics/literals.rs")),
+        "basics::text" => Some(include_str!("basics/text.rs")),
+        "collections" => Some(include_str!("collections.rs")),
+        "collections::growth" => Some(include_str!("collections/growth.rs")),
+        "collections::ring" => Some(include_str!("collections/ring.rs")),
+        "concurrency" => Some(include_str!("concurrency.rs")),
+        "concurrency::contention" => Some(include_str!("concurrency/contention.rs")),
+        "concurrency::deadlock" => Some(include_str!("concurrency/deadlock.rs")),
+        "concurrency::pipeline" => Some(include_str!("concurrency/pipeline.rs")),
+        "concurrency::primitives" => Some(include_str!("concurrency/primitives.rs")),
+        "concurrency::workers" => Some(include_str!("concurrency/workers.rs")),
+        "iterators" => Some(include_str!("iterators.rs")),
+        "ownership" => Some(include_str!("ownership.rs")),
+        "serialization" => Some(include_str!("serialization.rs")),
+        "smart_pointers" => Some(include_str!("smart_pointers.rs")),
+        "smart_pointers::layout" => Some(include_str!("smart_pointers/layout.rs")),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub struct Listing {
+    pub first_line: usize,
+    pub lines: Vec<&'static str>,
+}
+
+// finds a top level `fn name(` (optionally `pub` and/or `async`) and takes
+// every line up to the closing brace in the first column, which rustfmt guarantees
+pub fn extract(source: &'static str, name: &str) -> Option<Listing> {
+    let signatures = [
+        format!("fn {name}("),
+        format!("async fn {name}("),
+        format!("pub fn {name}("),
+        format!("pub async fn {name}("),
+    ];
+    let mut lines = source.lines().enumerate();
+    let (start, first) = lines.find(|(_, line)| {
+        signatures
+            .iter()
+            .any(|signature| line.starts_with(signature.as_str()))
+    })?;
+
+    let mut body = vec![first];
+    if !first.trim_end().ends_with('}') {
+        for (_, line) in lines {
+            body.push(line);
+            if line == "}" {
+                break;
+            }
+        }
+    }
+
+    Some(Listing {
+        first_line: start + 1,
+        lines: body,
+    })
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (offset, line) in self.lines.iter().enumerate() {
+            writeln!(f, "{:>4} | {}", self.first_line + offset, line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_finds_whole_function() {
+        let source = source("smart_pointers").unwrap();
+        let listing = extract(source, "cell_refcel").unwrap();
+        assert_eq!(listing.lines[0], "pub fn cell_refcel() {");
+        assert_eq!(*listing.lines.last().unwrap(), "}");
+        assert!(listing
+            .lines
+            .iter()
+            .any(|line| line.contains("RefCell::new(0)")));
+        assert_eq!(
+            source.lines().nth(listing.first_line - 1),
+            Some("pub fn cell_refcel() {")
+        );
+    }
+
+    #[test]
+    fn extract_handles_async_and_unknown_functions() {
+        let source = source("asynchronous").unwrap();
+        let listing = extract(source, "future_blocks").unwrap();
+        assert_eq!(listing.lines[0], "pub async fn future_blocks() {");
+        assert!(extract(source, "does_not_exist").is_none());
+    }
+}
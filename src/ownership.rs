@@ -0,0 +1,76 @@
+use std::mem;
+
+pub fn string_factory() -> String {
+    let large = r#"313213j123 "bla" // \n \t jlslkjdsf sdf kjdsflkj"#.to_string();
+    let reference = &large;
+    println!(
+        "reference points to large string on heap address: {:p}",
+        reference
+    );
+    println!("bytesize of String is {}", large.len());
+    println!("bytesize of reference is {}", mem::size_of_val(&large));
+    println!("bytes representation of large is {:?}", large.as_bytes());
+
+    // &large // cannot return reference to object that is owned by this function
+    large // can return the object, passing the ownership to caller
+}
+
+pub fn references() {
+    let value = 7;
+    let reference = &7;
+
+    //println!("value and reference equality: {}", value == reference);
+    println!("value and dereferenced equality: {}", value == *reference);
+}
+
+pub fn moving_references() -> String {
+    let mut string_object_created_in_method = string_factory();
+    string_object_created_in_method.push('?'); //
+    println!("mutated string {}", string_object_created_in_method);
+
+    print_without_passing_ownership(&string_object_created_in_method);
+
+    string_object_created_in_method
+}
+
+fn print_without_passing_ownership(string_reference: &String) {
+    // string_reference.push('!'); // -> can't mutate value of passed reference
+    println!("printing without mutating: {string_reference}");
+}
+
+#[allow(clippy::useless_vec, clippy::assign_op_pattern)]
+pub fn lifetimes() {
+    #[derive(Debug)]
+    struct City<'a> {
+        name: &'a String,
+        population: u32,
+    }
+
+    impl City<'_> {
+        fn grow(&mut self) {
+            self.population = self.population * 2;
+        }
+    }
+
+    let cities = vec!["Purmerend".to_string(), "Amsterdam".to_string()];
+
+    let mut my_city = City {
+        name: &cities[0],
+        population: 1800,
+    };
+
+    dbg!(my_city.name);
+    dbg!(my_city.population);
+    my_city.grow();
+    dbg!(my_city.population);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn string_factory_returns_string() {
+        let result = string_factory();
+        assert_eq!(result.len(), 48);
+    }
+}
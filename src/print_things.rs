@@ -0,0 +1,24 @@
+#[derive(Debug)]
+pub struct PrintThings {
+    pub first: u8,
+    second: u8,
+    third: u8,
+}
+impl PrintThings {
+    pub fn create(i: u8) -> Self {
+        Self {
+            first: i,
+            second: 2,
+            third: 3,
+        }
+    }
+    pub fn prints_one_thing<T: std::fmt::Display>(input: T) {
+        println!("{}", input)
+    }
+}
+
+impl std::fmt::Display for PrintThings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}, {}", self.first, self.second, self.third)
+    }
+}
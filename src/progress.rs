@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::exercises::EXERCISES;
+use crate::LESSONS;
+
+// timestamps are seconds since the unix epoch
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Progress {
+    pub lessons_viewed: BTreeMap<String, u64>,
+    pub exercises_passed: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TopicProgress {
+    pub topic: &'static str,
+    pub lessons_viewed: usize,
+    pub lessons: usize,
+    pub exercises_passed: usize,
+    pub exercises: usize,
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+impl Progress {
+    // ~/.config/learning-rust/progress.json on linux
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("learning-rust").join("progress.json"))
+    }
+
+    // a missing file is no progress yet rather than an error
+    pub fn load(path: &Path) -> io::Result<Progress> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        fs::write(path, json)
+    }
+
+    pub fn view_lesson(&mut self, name: &str, at: u64) {
+        self.lessons_viewed.insert(name.to_string(), at);
+    }
+
+    // keeps the first time an exercise passed
+    pub fn pass_exercise(&mut self, name: &str, at: u64) {
+        self.exercises_passed.entry(name.to_string()).or_insert(at);
+    }
+
+    pub fn per_topic(&self) -> Vec<TopicProgress> {
+        let mut topics: Vec<&'static str> = LESSONS
+            .iter()
+            .map(|lesson| lesson.topic())
+            .chain(EXERCISES.iter().map(|exercise| exercise.topic))
+            .collect();
+        topics.sort();
+        topics.dedup();
+
+        topics
+            .into_iter()
+            .map(|topic| {
+                let lessons = LESSONS.iter().filter(|lesson| lesson.topic() == topic);
+                let exercises = EXERCISES.iter().filter(|exercise| exercise.topic == topic);
+                TopicProgress {
+                    topic,
+                    lessons_viewed: lessons
+                        .clone()
+                        .filter(|lesson| self.lessons_viewed.contains_key(lesson.name))
+                        .count(),
+                    lessons: lessons.count(),
+                    exercises_passed: exercises
+                        .clone()
+                        .filter(|exercise| self.exercises_passed.contains_key(exercise.name))
+                        .count(),
+                    exercises: exercises.count(),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_round_trips_through_json_file() {
+        let path = std::env::temp_dir()
+            .join(format!("learning-rust-{}", std::process::id()))
+            .join("progress.json");
+        assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+
+        let mut progress = Progress::default();
+        progress.view_lesson("cell_refcel", 1_726_000_000);
+        progress.pass_exercise("take_fifth", 1_726_000_100);
+        progress.pass_exercise("take_fifth", 1_726_000_200);
+        progress.save(&path).unwrap();
+
+        let loaded = Progress::load(&path).unwrap();
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        assert_eq!(loaded, progress);
+        assert_eq!(loaded.exercises_passed["take_fifth"], 1_726_000_100);
+    }
+
+    #[test]
+    fn per_topic_counts_lessons_and_exercises() {
+        let mut progress = Progress::default();
+        progress.view_lesson("mutex", 1);
+        progress.view_lesson("channels", 2);
+        progress.pass_exercise("rotate", 3);
+
+        let topics = progress.per_topic();
+        let concurrency = topics.iter().find(|t| t.topic == "concurrency").unwrap();
+        assert_eq!(concurrency.lessons_viewed, 2);
+        assert_eq!(concurrency.exercises, 0);
+        let collections = topics.iter().find(|t| t.topic == "collections").unwrap();
+        assert_eq!(collections.exercises_passed, 1);
+        assert_eq!(collections.exercises, 1);
+    }
+}
@@ -0,0 +1,12 @@
+pub fn serde() {
+    use serde::{Serialize, Deserialize};
+
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Point {
+        x: u8,
+        y: u8
+    }
+    let point = Point{x: 1, y:2};
+    println!("serde_json::to_string {}", serde_json::to_string(&point).unwrap());
+
+}
@@ -0,0 +1,99 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt;
+
+pub mod layout;
+
+// Phone is only ever printed, which doesn't count as reading its fields
+#[allow(dead_code)]
+pub fn cell_refcel() {
+    #[derive(Debug)]
+    struct Phone {
+        name: &'static str,
+        weight_gram: u32,
+        on_sale: Cell<bool>,
+        bought_by_customer_id: RefCell<u32>,
+    }
+
+    let nokia_3330 = Phone {
+        name: "Nokia 3310",
+        weight_gram: 400,
+        on_sale: Cell::new(false),
+        bought_by_customer_id: RefCell::new(0),
+    };
+
+    dbg!(&nokia_3330.on_sale);
+    nokia_3330.on_sale.set(true);
+    dbg!(&nokia_3330.on_sale);
+    dbg!(&nokia_3330.bought_by_customer_id);
+    nokia_3330.bought_by_customer_id.replace(101332);
+    dbg!(&nokia_3330.bought_by_customer_id);
+
+    let borrowed_bought_value = nokia_3330.bought_by_customer_id.borrow_mut();
+    //let borrowed_bought_value2 = nokia_3330.bought_by_customer_id.borrow_mut(); //this would panic runtime
+    dbg!(borrowed_bought_value);
+}
+
+#[derive(Debug)]
+pub struct LargeStruct {
+    pub data: [u8; 1_000_000],
+}
+
+pub fn boxed_large_struct() -> Box<LargeStruct> {
+    Box::new(LargeStruct {
+        data: [0; 1_000_000],
+    })
+}
+
+pub fn box_heap() {
+    let large_object = boxed_large_struct();
+    println!("{:?}", large_object.type_id());
+}
+
+// most variants of EnumOfNumbers are never built
+#[allow(dead_code)]
+pub fn dyn_trait() {
+    trait JustATrait: fmt::Debug {}
+
+    enum EnumOfNumbers {
+        I8(i8),
+        AnotherI8(i8),
+        OneMoreI8(i8),
+    }
+    impl JustATrait for EnumOfNumbers {}
+
+    impl fmt::Debug for EnumOfNumbers {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                EnumOfNumbers::I8(value) => write!(f, "I8({})", value),
+                EnumOfNumbers::AnotherI8(value) => write!(f, "AnotherI8({})", value),
+                EnumOfNumbers::OneMoreI8(value) => write!(f, "OneMoreI8({})", value),
+            }
+        }
+    }
+
+    fn returns_a_trait() -> Box<dyn JustATrait> {
+        let some_enum = EnumOfNumbers::I8(8);
+        Box::new(some_enum)
+    }
+
+    let my_trait = returns_a_trait();
+    println!("formatted trait with dyn {:?}", my_trait)
+}
+
+pub fn dereferencing() {
+    use std::ops::Deref;
+
+    struct HoldsAnumber(u8);
+    impl Deref for HoldsAnumber {
+        type Target = u8;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    let my_number = HoldsAnumber(10);
+    println!("dereferencing my own struct: {}", *my_number + 20)
+}
@@ -0,0 +1,209 @@
+use std::fmt;
+use std::mem::{align_of, offset_of, size_of};
+
+use crate::iterators::Measurement;
+
+pub struct Field {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+}
+
+pub struct TypeLayout {
+    pub name: &'static str,
+    pub size: usize,
+    pub align: usize,
+    pub fields: Vec<Field>,
+}
+
+impl TypeLayout {
+    pub fn of<T>(name: &'static str) -> Self {
+        TypeLayout {
+            name,
+            size: size_of::<T>(),
+            align: align_of::<T>(),
+            fields: vec![],
+        }
+    }
+
+    pub fn with_fields(mut self, mut fields: Vec<Field>) -> Self {
+        fields.sort_by_key(|field| field.offset);
+        self.fields = fields;
+        self
+    }
+
+    // bytes that belong to no field, only known when we listed the fields
+    pub fn padding(&self) -> Option<usize> {
+        if self.fields.is_empty() {
+            return None;
+        }
+        Some(self.size - self.fields.iter().map(|field| field.size).sum::<usize>())
+    }
+}
+
+impl fmt::Display for TypeLayout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<24} size {:>2}  align {}",
+            self.name, self.size, self.align
+        )?;
+        for field in &self.fields {
+            writeln!(
+                f,
+                "  {:<22} offset {:>2}  size {:>2}",
+                field.name, field.offset, field.size
+            )?;
+        }
+        if let Some(padding) = self.padding().filter(|padding| *padding > 0) {
+            writeln!(f, "  {:<22} {padding} bytes", "padding")?;
+        }
+        Ok(())
+    }
+}
+
+// only used to name the type of a field, the closure is never called
+fn field_size<T, F>(_: impl Fn(&T) -> &F) -> usize {
+    size_of::<F>()
+}
+
+macro_rules! fields {
+    ($type:ty { $($field:ident),* }) => {
+        vec![$(Field {
+            name: stringify!($field),
+            offset: offset_of!($type, $field),
+            size: field_size(|value: &$type| &value.$field),
+        }),*]
+    };
+}
+
+// the same Device as in structs_enums_and_impl(), which keeps its own inside the function
+#[allow(dead_code)]
+pub enum DeviceType {
+    Synth,
+    Controller,
+    Mixer,
+}
+
+#[allow(dead_code)]
+pub struct Device {
+    device_type: DeviceType,
+    power_usage_amps: f32,
+    name: String,
+    connected: bool,
+}
+
+// repr(C) keeps the fields in declaration order, so the padding between them stays
+#[allow(dead_code)]
+#[repr(C)]
+pub struct CDevice {
+    device_type: DeviceType,
+    power_usage_amps: f32,
+    name: String,
+    connected: bool,
+}
+
+// every bit pattern of f32 and u32 is valid, so there is no niche and the tag needs room
+#[allow(dead_code)]
+pub enum Reading {
+    Celsius(f32),
+    Raw(u32),
+}
+
+// String's capacity can never exceed isize::MAX, so Empty hides in a value it cannot have
+#[allow(dead_code)]
+pub enum Payload {
+    Text(String),
+    Empty,
+}
+
+pub fn layouts() -> Vec<TypeLayout> {
+    vec![
+        TypeLayout::of::<String>("String"),
+        TypeLayout::of::<&str>("&str"),
+        TypeLayout::of::<Option<&str>>("Option<&str>"),
+        TypeLayout::of::<Box<u64>>("Box<u64>"),
+        TypeLayout::of::<Option<Box<u64>>>("Option<Box<u64>>"),
+        TypeLayout::of::<u32>("u32"),
+        TypeLayout::of::<Option<u32>>("Option<u32>"),
+        TypeLayout::of::<char>("char"),
+        TypeLayout::of::<Option<char>>("Option<char>"),
+        TypeLayout::of::<DeviceType>("DeviceType"),
+        TypeLayout::of::<Option<DeviceType>>("Option<DeviceType>"),
+        TypeLayout::of::<Reading>("Reading"),
+        TypeLayout::of::<Option<Reading>>("Option<Reading>"),
+        TypeLayout::of::<Payload>("Payload"),
+        TypeLayout::of::<Measurement>("Measurement").with_fields(fields!(Measurement {
+            date,
+            measurement,
+            value
+        })),
+        TypeLayout::of::<Device>("Device").with_fields(fields!(Device {
+            device_type,
+            power_usage_amps,
+            name,
+            connected
+        })),
+        TypeLayout::of::<CDevice>("#[repr(C)] Device").with_fields(fields!(CDevice {
+            device_type,
+            power_usage_amps,
+            name,
+            connected
+        })),
+    ]
+}
+
+pub fn memory_layout() {
+    for layout in layouts() {
+        print!("{layout}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(name: &str) -> TypeLayout {
+        layouts()
+            .into_iter()
+            .find(|layout| layout.name == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn niches_make_options_free() {
+        assert_eq!(layout("Option<Box<u64>>").size, layout("Box<u64>").size);
+        assert_eq!(layout("Option<&str>").size, layout("&str").size);
+        assert_eq!(layout("Option<char>").size, layout("char").size);
+        assert_eq!(layout("Option<DeviceType>").size, 1);
+        assert_eq!(layout("Payload").size, layout("String").size);
+        // no niche, the tag costs a whole alignment unit
+        assert_eq!(layout("Option<u32>").size, 8);
+        assert_eq!(layout("Reading").size, 8);
+    }
+
+    #[test]
+    fn reordering_fields_saves_padding() {
+        // String is three words, so its size and alignment follow the pointer width
+        let string = size_of::<String>();
+        let align = align_of::<String>().max(align_of::<f32>());
+        let c_device = layout("#[repr(C)] Device");
+        let offsets = c_device
+            .fields
+            .iter()
+            .map(|field| (field.name, field.offset))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            offsets,
+            vec![
+                ("device_type", 0),
+                ("power_usage_amps", 4),
+                ("name", 8),
+                ("connected", 8 + string)
+            ]
+        );
+        // three bytes after device_type, the rest after connected up to the alignment
+        assert_eq!(c_device.padding(), Some(3 + align - 1));
+        assert!(layout("Device").size < c_device.size);
+    }
+}
@@ -0,0 +1,42 @@
+// cargo test --features count-allocations --test allocations
+//
+// every test binary picks its own global allocator, so this one counts
+// everything it does; a single test keeps other tests from being counted too
+#![cfg(feature = "count-allocations")]
+
+use learning_rust::allocations::{measure, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn vec_patterns_allocate_as_expected() {
+    let (_, pushed) = measure(|| {
+        let mut numbers = Vec::new();
+        for number in 0..1000_u32 {
+            numbers.push(number);
+        }
+        numbers
+    });
+    let (_, with_capacity) = measure(|| {
+        let mut numbers = Vec::with_capacity(1000);
+        for number in 0..1000_u32 {
+            numbers.push(number);
+        }
+        numbers
+    });
+    // every time the capacity runs out the vec reallocates
+    assert!(pushed.count > 5);
+    assert_eq!(with_capacity.count, 1);
+    assert_eq!(with_capacity.bytes, 4000);
+
+    let (_, collected) = measure(|| (0..1000_u32).map(|number| number * 2).collect::<Vec<u32>>());
+    let (_, filtered) = measure(|| {
+        (0..1000_u32)
+            .filter(|number| number % 2 == 0)
+            .collect::<Vec<u32>>()
+    });
+    // map keeps the exact length, filter hides it so collect has to grow as it goes
+    assert_eq!(collected.count, 1);
+    assert!(filtered.count > 1);
+}
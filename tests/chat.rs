@@ -0,0 +1,46 @@
+#![cfg(not(loom))]
+
+use learning_rust::asynchronous::chat::{self, ChatClient};
+
+#[tokio::test]
+async fn lines_are_broadcast_to_every_other_client() {
+    let (address, server) = chat::start().await.unwrap();
+
+    let mut clients = vec![];
+    for id in 1..=4 {
+        let (client, welcome) = ChatClient::connect(address).await.unwrap();
+        assert_eq!(welcome, format!("welcome client {id}"));
+        clients.push(client);
+    }
+
+    clients[0].send("hello from one").await.unwrap();
+    for client in &mut clients[1..] {
+        assert_eq!(
+            client.next_line().await.unwrap(),
+            "client 1: hello from one"
+        );
+    }
+
+    clients[2].send("three here").await.unwrap();
+    for (index, client) in clients.iter_mut().enumerate() {
+        if index != 2 {
+            assert_eq!(client.next_line().await.unwrap(), "client 3: three here");
+        }
+    }
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn a_disconnected_client_does_not_stop_the_others() {
+    let (address, server) = chat::start().await.unwrap();
+    let (first, _) = ChatClient::connect(address).await.unwrap();
+    let (mut second, _) = ChatClient::connect(address).await.unwrap();
+    let (mut third, _) = ChatClient::connect(address).await.unwrap();
+    drop(first);
+
+    second.send("still here?").await.unwrap();
+    assert_eq!(third.next_line().await.unwrap(), "client 2: still here?");
+
+    server.abort();
+}
@@ -0,0 +1,15 @@
+use learning_rust::{basics, ownership, Lesson, LESSONS};
+
+#[test]
+fn every_lesson_has_a_listing() {
+    for lesson in LESSONS {
+        assert!(lesson.listing().is_some(), "no listing for {}", lesson.name);
+    }
+}
+
+#[test]
+fn lessons_can_be_called_directly() {
+    assert_eq!(basics::types(), '=');
+    assert_eq!(ownership::moving_references().pop(), Some('?'));
+    assert!(Lesson::find("cell_refcel").is_some());
+}
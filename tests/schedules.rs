@@ -0,0 +1,33 @@
+// RUSTFLAGS="--cfg loom" cargo test --release --test schedules
+//
+// loom runs every lesson below many times, each time picking a different
+// interleaving of the threads, until every schedule (up to the preemption
+// bound) has been tried; a failing schedule is reported with its trace
+#![cfg(loom)]
+
+use learning_rust::concurrency;
+
+fn explore(lesson: impl Fn() + Sync + Send + 'static) {
+    let mut builder = loom::model::Builder::new();
+    builder.preemption_bound = Some(2);
+    builder.check(lesson);
+}
+
+#[test]
+fn naive_counter_always_reaches_twenty() {
+    explore(|| assert_eq!(concurrency::naive_counter(), 20));
+}
+
+#[test]
+fn counter_with_handles_always_reaches_twenty() {
+    explore(|| assert_eq!(concurrency::counter_with_handles(), 20));
+}
+
+#[test]
+fn every_channel_message_arrives() {
+    explore(|| {
+        let mut messages = concurrency::channel_messages();
+        messages.sort();
+        assert_eq!(messages, vec!["Send a &str", "Send another &str"]);
+    });
+}